@@ -0,0 +1,91 @@
+use core::mem::MaybeUninit;
+
+use super::storage::Storage;
+use crate::{
+    error::TryReserveError,
+    storage::{
+        alloc::{Allocator, Global},
+        AllocStorage, Storage as AllocStorageApi,
+    },
+};
+
+/// A heap-backed [`ArrayVec`](super::ArrayVec) storage that actually
+/// reallocates, reusing [`AllocStorage`]'s amortized doubling growth policy.
+///
+/// Unlike plugging [`AllocStorage`] directly into [`Vec`](crate::Vec),
+/// [`HeapStorage`] deallocates its buffer itself on [`Drop`], since
+/// [`ArrayVec`](super::ArrayVec) only ever clears its elements and otherwise
+/// relies on its storage's own destructor.
+pub struct HeapStorage<T, A: Allocator = Global> {
+    inner: AllocStorage<T, A>,
+}
+
+impl<T> HeapStorage<T, Global> {
+    /// Create a new, empty [`HeapStorage`] backed by the global allocator.
+    ///
+    /// This does not allocate until elements are pushed.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> HeapStorage<T, Global> {
+        HeapStorage {
+            inner: AllocStorage::new(),
+        }
+    }
+}
+
+impl<T, A: Allocator> HeapStorage<T, A> {
+    /// Create a new, empty [`HeapStorage`] backed by `alloc`.
+    ///
+    /// This does not allocate until elements are pushed.
+    #[inline]
+    #[must_use]
+    pub const fn new_in(alloc: A) -> HeapStorage<T, A> {
+        HeapStorage {
+            inner: AllocStorage::new_in(alloc),
+        }
+    }
+}
+
+// SAFETY: `as_uninit_slice`/`as_uninit_slice_mut` expose exactly `inner`'s
+//         current capacity, and `try_grow` only ever grows that same buffer.
+unsafe impl<T, A: Allocator> Storage for HeapStorage<T, A> {
+    type Item = T;
+
+    #[inline]
+    fn as_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        let cap = AllocStorageApi::capacity(&self.inner);
+
+        // SAFETY: `inner`'s base pointer is valid and properly aligned for `cap` elements.
+        unsafe { core::slice::from_raw_parts(AllocStorageApi::base_ptr(&self.inner).cast(), cap) }
+    }
+
+    #[inline]
+    fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let cap = AllocStorageApi::capacity(&self.inner);
+
+        // SAFETY: `inner`'s base pointer is valid and properly aligned for `cap` elements.
+        unsafe {
+            core::slice::from_raw_parts_mut(AllocStorageApi::base_ptr_mut(&mut self.inner).cast(), cap)
+        }
+    }
+
+    #[inline]
+    fn try_grow(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let cap = AllocStorageApi::capacity(&self.inner);
+
+        // Reuse `AllocStorage`'s own amortized doubling growth policy, treating
+        // the entire current capacity as the "length" to grow past: we always
+        // reallocate wholesale rather than tracking a separate occupied length.
+        AllocStorageApi::grow(&mut self.inner, cap, additional)
+    }
+}
+
+impl<T, A: Allocator> Drop for HeapStorage<T, A> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `ArrayVec::drop` has already dropped every live element before
+        //         its `storage` field (and thus this) is dropped, and `inner` is
+        //         never deallocated anywhere else.
+        unsafe { AllocStorageApi::dealloc(&mut self.inner) }
+    }
+}
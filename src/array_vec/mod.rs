@@ -0,0 +1,560 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    hash::Hash,
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut, RangeBounds},
+    ptr, slice,
+};
+
+use crate::{error::TryReserveError, vec_impl::VecImpl, Uninit};
+
+mod storage;
+pub use storage::Storage;
+
+#[cfg(feature = "alloc")]
+mod heap;
+#[cfg(feature = "alloc")]
+pub use heap::HeapStorage;
+
+/// An owning, fixed-capacity vector generic over its backing [`Storage`].
+///
+/// Unlike [`SliceVec`](crate::SliceVec), which only ever borrows a fixed
+/// `&'a mut [MaybeUninit<T>]`, [`ArrayVec`] owns wherever it puts its
+/// elements: an inline `[MaybeUninit<T>; N]` for a truly embedded,
+/// non-growable vector, or, under the `alloc` feature, a [`HeapStorage`]
+/// that reallocates like [`Vec`](crate::Vec) does.
+pub struct ArrayVec<S: Storage> {
+    storage: S,
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<[MaybeUninit<T>; N]> {
+    /// Create a new, empty [`ArrayVec`] embedding its elements inline.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        ArrayVec {
+            storage: crate::uninit_array(),
+            len: 0,
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<[MaybeUninit<T>; N]> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ArrayVec<HeapStorage<T>> {
+    /// Create a new, empty [`ArrayVec`] backed by the global allocator.
+    ///
+    /// This does not allocate until elements are pushed.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        ArrayVec {
+            storage: HeapStorage::new(),
+            len: 0,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Default for ArrayVec<HeapStorage<T>> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Storage> ArrayVec<S> {
+    /// Create a new [`ArrayVec`] from an already-constructed, empty [`Storage`].
+    #[inline]
+    #[must_use]
+    pub fn with_storage(storage: S) -> Self {
+        ArrayVec { storage, len: 0 }
+    }
+
+    /// Get the length of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        VecImpl::len(self)
+    }
+
+    /// Returns whether this vector is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        VecImpl::is_empty(self)
+    }
+
+    /// Get the capacity of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        VecImpl::capacity(self)
+    }
+
+    /// Get the remaining capacity of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        VecImpl::remaining(self)
+    }
+
+    /// Returns whether this vector is full.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Get a raw pointer to this vector's buffer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr(&self) -> *const S::Item {
+        VecImpl::as_ptr(self)
+    }
+
+    /// Get a mutable raw pointer to this vector's buffer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr_mut(&mut self) -> *mut S::Item {
+        VecImpl::as_ptr_mut(self)
+    }
+
+    /// Get a slice to the initialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice(&self) -> &[S::Item] {
+        VecImpl::as_slice(self)
+    }
+
+    /// Get a mutable slice to the initialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice_mut(&mut self) -> &mut [S::Item] {
+        VecImpl::as_slice_mut(self)
+    }
+
+    /// Get a slice to the remaining uninitialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_remaining(&self) -> &[MaybeUninit<S::Item>] {
+        VecImpl::as_remaining(self)
+    }
+
+    /// Get a mutable slice to the remaining uninitialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_remaining_mut(&mut self) -> &mut [MaybeUninit<S::Item>] {
+        VecImpl::as_remaining_mut(self)
+    }
+
+    /// Split this vector into its initialized slice, and remaining uninitialized slice.
+    #[inline]
+    #[must_use]
+    pub fn as_parts(&self) -> (&[S::Item], &[MaybeUninit<S::Item>]) {
+        let (init, uninit) = self.storage.as_uninit_slice().split_at(self.len);
+        let init = unsafe { init.assume_init_ref() };
+
+        (init, uninit)
+    }
+
+    /// Split this vector mutably into its initialized slice, and remaining uninitialized slice.
+    #[inline]
+    #[must_use]
+    pub fn as_parts_mut(&mut self) -> (&mut [S::Item], &mut [MaybeUninit<S::Item>]) {
+        let len = self.len;
+        let (init, uninit) = self.storage.as_uninit_slice_mut().split_at_mut(len);
+        let init = unsafe { init.assume_init_mut() };
+
+        (init, uninit)
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        VecImpl::truncate(self, new_len)
+    }
+
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        VecImpl::clear(self)
+    }
+
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        unsafe { VecImpl::set_len(self, new_len) }
+    }
+
+    #[inline(always)]
+    pub unsafe fn push_unchecked(&mut self, item: S::Item) {
+        unsafe { VecImpl::push_unchecked(self, item) }
+    }
+
+    #[inline(always)]
+    pub fn try_push(&mut self, item: S::Item) -> Result<(), (S::Item, TryReserveError)> {
+        VecImpl::try_push(self, item)
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    pub fn push(&mut self, item: S::Item) {
+        VecImpl::push(self, item)
+    }
+
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        VecImpl::try_reserve(self, additional)
+    }
+
+    /// Try to insert an element at `index`, shifting every element after it
+    /// one slot to the right.
+    #[track_caller]
+    pub fn try_insert(
+        &mut self,
+        index: usize,
+        item: S::Item,
+    ) -> Result<(), (S::Item, TryReserveError)> {
+        VecImpl::try_insert(self, index, item)
+    }
+
+    /// Insert an element at `index`, shifting every element after it one
+    /// slot to the right.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, item: S::Item) {
+        VecImpl::insert(self, index, item);
+    }
+
+    /// Remove and return the element at `index`, shifting every element
+    /// after it one slot to the left.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> S::Item {
+        VecImpl::remove(self, index)
+    }
+
+    /// Remove and return the element at `index`, moving the last element
+    /// into its place instead of shifting the tail down.
+    ///
+    /// This does not preserve ordering, but is `O(1)` rather than `O(n)`.
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> S::Item {
+        VecImpl::swap_remove(self, index)
+    }
+
+    /// Resize this vector in place so that its length is `new_len`,
+    /// producing each newly added element by calling `f`.
+    ///
+    /// If `new_len` is less than the current length, the vector is
+    /// truncated instead.
+    #[track_caller]
+    pub fn resize_with<F: FnMut() -> S::Item>(&mut self, new_len: usize, f: F) {
+        VecImpl::resize_with(self, new_len, f);
+    }
+
+    /// Try to resize this vector in place so that its length is `new_len`,
+    /// filling any newly added slots by cloning `value`.
+    #[track_caller]
+    pub fn try_resize(&mut self, new_len: usize, value: S::Item) -> Result<(), TryReserveError>
+    where
+        S::Item: Clone,
+    {
+        VecImpl::try_resize(self, new_len, value)
+    }
+
+    /// Try to clone every element of `slice` onto the end of this vector.
+    #[track_caller]
+    pub fn try_extend_from_slice(&mut self, slice: &[S::Item]) -> Result<(), TryReserveError>
+    where
+        S::Item: Clone,
+    {
+        VecImpl::try_extend_from_slice(self, slice)
+    }
+
+    /// Remove consecutive elements for which `same_bucket` returns `true`,
+    /// keeping the first element of each run.
+    ///
+    /// If `same_bucket` panics, the elements already scanned are left
+    /// compacted and the vector's length updated accordingly.
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut S::Item, &mut S::Item) -> bool,
+    {
+        VecImpl::dedup_by(self, same_bucket);
+    }
+
+    /// Remove consecutive duplicate elements, keeping the first of each run.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        S::Item: PartialEq,
+    {
+        VecImpl::dedup(self);
+    }
+
+    /// Remove consecutive elements that map to the same key, keeping the
+    /// first of each run.
+    #[inline]
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&mut S::Item) -> K,
+        K: PartialEq,
+    {
+        VecImpl::dedup_by_key(self, key);
+    }
+
+    /// Create an iterator which uses a closure to determine which elements to
+    /// remove, yielding those elements and leaving the rest compacted in
+    /// place.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the
+    /// not-yet-scanned tail is left in place, shifted back to directly follow
+    /// the already-compacted prefix, without invoking the predicate again.
+    pub fn extract_if<'s, F>(&'s mut self, pred: F) -> impl Iterator<Item = S::Item> + 's
+    where
+        F: FnMut(&mut S::Item) -> bool + 's,
+    {
+        VecImpl::extract_if(self, pred)
+    }
+
+    /// Remove the given range from this vector, returning a draining
+    /// iterator over the removed elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is past the length of the vector.
+    #[track_caller]
+    pub fn drain<R>(&mut self, range: R) -> impl Iterator<Item = S::Item> + '_
+    where
+        R: RangeBounds<usize>,
+    {
+        VecImpl::drain(self, range)
+    }
+}
+
+unsafe impl<S: Storage> VecImpl for ArrayVec<S> {
+    type Item = S::Item;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= self.capacity());
+        self.len = len;
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.storage.as_uninit_slice().len()
+    }
+
+    #[inline(always)]
+    fn grow(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.storage.try_grow(additional)
+    }
+
+    #[inline(always)]
+    fn grow_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        // `Storage` doesn't distinguish an exact growth request from an
+        // amortized one; it's up to each implementor to pick a policy.
+        self.storage.try_grow(additional)
+    }
+
+    #[inline(always)]
+    fn as_ptr(&self) -> *const Self::Item {
+        self.storage.as_uninit_slice().as_ptr().cast()
+    }
+
+    #[inline(always)]
+    fn as_ptr_mut(&mut self) -> *mut Self::Item {
+        self.storage.as_uninit_slice_mut().as_mut_ptr().cast()
+    }
+}
+
+impl<S: Storage> Deref for ArrayVec<S> {
+    type Target = [S::Item];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<S: Storage> DerefMut for ArrayVec<S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_slice_mut()
+    }
+}
+
+impl<S: Storage> Borrow<[S::Item]> for ArrayVec<S> {
+    #[inline(always)]
+    fn borrow(&self) -> &[S::Item] {
+        self
+    }
+}
+
+impl<S: Storage> BorrowMut<[S::Item]> for ArrayVec<S> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut [S::Item] {
+        self
+    }
+}
+
+impl<S: Storage> AsRef<[S::Item]> for ArrayVec<S> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[S::Item] {
+        self
+    }
+}
+
+impl<S: Storage> AsMut<[S::Item]> for ArrayVec<S> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [S::Item] {
+        self
+    }
+}
+
+impl<S: Storage> Hash for ArrayVec<S>
+where
+    S::Item: Hash,
+{
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
+impl<S: Storage> PartialEq for ArrayVec<S>
+where
+    S::Item: PartialEq,
+{
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.deref().eq(other.deref())
+    }
+
+    #[inline(always)]
+    fn ne(&self, other: &Self) -> bool {
+        self.deref().ne(other.deref())
+    }
+}
+
+impl<S: Storage> Eq for ArrayVec<S> where S::Item: Eq {}
+
+impl<S: Storage> PartialOrd for ArrayVec<S>
+where
+    S::Item: PartialOrd,
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+}
+
+impl<S: Storage> Ord for ArrayVec<S>
+where
+    S::Item: Ord,
+{
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<'b, S: Storage> IntoIterator for &'b ArrayVec<S> {
+    type Item = &'b S::Item;
+    type IntoIter = slice::Iter<'b, S::Item>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'b, S: Storage> IntoIterator for &'b mut ArrayVec<S> {
+    type Item = &'b mut S::Item;
+    type IntoIter = slice::IterMut<'b, S::Item>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<S: Storage> fmt::Debug for ArrayVec<S>
+where
+    S::Item: fmt::Debug,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<S: Storage> Drop for ArrayVec<S> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        let elems: *mut [S::Item] = self.as_slice_mut();
+
+        unsafe { elems.drop_in_place() }
+    }
+}
+
+impl<S: Storage<Item = u8>> fmt::Write for ArrayVec<S> {
+    /// Write as many bytes of `s` as fit, failing instead of truncating if
+    /// the remaining capacity is too small.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+
+        if bytes.len() > self.remaining() {
+            return Err(fmt::Error);
+        }
+
+        let len = self.len();
+
+        // SAFETY: just checked that `bytes` fits within the remaining capacity.
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), self.as_ptr_mut().add(len), bytes.len());
+            self.set_len(len + bytes.len());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S: Storage<Item = u8>> std::io::Write for ArrayVec<S> {
+    /// Write as many bytes of `buf` as fit, reporting how many were written
+    /// rather than failing when the remaining capacity runs out.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.remaining());
+        let len = self.len();
+
+        // SAFETY: `n` is clamped to the remaining capacity.
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), self.as_ptr_mut().add(len), n);
+            self.set_len(len + n);
+        }
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use core::mem::MaybeUninit;
+
+use crate::error::TryReserveError;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Somewhere to put the elements of an [`ArrayVec`](super::ArrayVec): an inline
+/// array, a borrowed slice, or a heap-backed buffer, unified behind one
+/// abstraction in the spirit of gimli's `ArrayLike`.
+///
+/// This is deliberately simpler than [`Storage`](crate::Storage), which
+/// backs [`Vec`](crate::Vec) and tracks its own base pointer directly: here,
+/// growth is always relative to the *entire* current backing buffer rather
+/// than a tracked length, which is enough for storages that either can't
+/// grow at all (a fixed-size array) or always reallocate wholesale (a heap
+/// buffer).
+///
+/// This trait is sealed: it can only be implemented by types within this crate.
+pub unsafe trait Storage: sealed::Sealed {
+    /// The type of element this storage holds.
+    type Item;
+
+    /// Get a slice over this storage's entire, potentially uninitialized buffer.
+    #[must_use]
+    fn as_uninit_slice(&self) -> &[MaybeUninit<Self::Item>];
+
+    /// Get a mutable slice over this storage's entire, potentially
+    /// uninitialized buffer.
+    #[must_use]
+    fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<Self::Item>];
+
+    /// Attempt to grow this storage's buffer by `additional` elements.
+    ///
+    /// This should only ever be called when we know we need to resize the buffer.
+    fn try_grow(&mut self, additional: usize) -> Result<(), TryReserveError>;
+}
+
+impl<T, const N: usize> sealed::Sealed for [MaybeUninit<T>; N] {}
+
+#[cfg(feature = "alloc")]
+impl<T, A: crate::storage::alloc::Allocator> sealed::Sealed for super::heap::HeapStorage<T, A> {}
+
+// SAFETY: `as_uninit_slice`/`as_uninit_slice_mut` expose exactly the `N`
+//         elements backed by this array.
+unsafe impl<T, const N: usize> Storage for [MaybeUninit<T>; N] {
+    type Item = T;
+
+    #[inline(always)]
+    fn as_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self
+    }
+
+    #[inline(always)]
+    fn try_grow(&mut self, _additional: usize) -> Result<(), TryReserveError> {
+        // A fixed-size inline array can never grow.
+        Err(TryReserveError::CapacityOverflow)
+    }
+}
@@ -0,0 +1,103 @@
+use core::{marker::PhantomData, mem, mem::MaybeUninit, ptr};
+
+/// Tracks which fields of a piecewise-initialized value have been written so
+/// far, dropping exactly those fields if construction is abandoned before
+/// completion (a panic, or bailing out with an early `Err`).
+///
+/// This is the primitive behind [`Owned::try_init`](crate::Owned::try_init)'s
+/// support for initializing a struct field-by-field: each [`FieldGuard::write`]
+/// records a field as live, and until [`FieldGuard::forget`] is called the
+/// guard's [`Drop`] undoes any of those writes that are still outstanding.
+///
+/// `N` is the maximum number of fields this guard can track; it should match
+/// the number of fields being initialized through it.
+pub struct FieldGuard<'a, const N: usize> {
+    base: *mut u8,
+    fields: [MaybeUninit<(usize, unsafe fn(*mut u8))>; N],
+    len: usize,
+    _marker: PhantomData<&'a mut ()>,
+}
+
+impl<'a, const N: usize> FieldGuard<'a, N> {
+    /// Create a new [`FieldGuard`] over the allocation starting at `base`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `base` is a valid pointer to (at least)
+    /// every field that will be written through this guard, for the
+    /// duration of `'a`.
+    #[inline]
+    #[must_use]
+    pub unsafe fn new(base: *mut u8) -> FieldGuard<'a, N> {
+        FieldGuard {
+            base,
+            fields: [MaybeUninit::uninit(); N],
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Write `value` to the field at `offset` (in bytes, from the guard's
+    /// base pointer), recording it so it gets dropped if the guard is
+    /// dropped before [`FieldGuard::forget`] is called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this guard is already tracking its maximum of `N` fields.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure:
+    ///
+    /// - `offset` is in-bounds of the allocation passed to
+    ///   [`FieldGuard::new`] and is properly aligned for `T`.
+    /// - `offset` has not already been written through this guard.
+    #[inline]
+    pub unsafe fn write<T>(&mut self, offset: usize, value: T) {
+        assert!(
+            self.len < N,
+            "`FieldGuard` is already tracking its maximum of {N} fields"
+        );
+
+        #[inline(always)]
+        unsafe fn drop_field<T>(ptr: *mut u8) {
+            unsafe { ptr::drop_in_place(ptr.cast::<T>()) }
+        }
+
+        // SAFETY: The caller ensures `offset` is in-bounds and properly aligned for `T`.
+        unsafe { self.base.add(offset).cast::<T>().write(value) };
+
+        self.fields[self.len] = MaybeUninit::new((offset, drop_field::<T> as unsafe fn(*mut u8)));
+        self.len += 1;
+    }
+
+    /// Consume the guard without dropping any of the fields it recorded.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that every field making up the value being
+    /// constructed has been initialized, either through this guard or
+    /// otherwise.
+    #[inline]
+    pub unsafe fn forget(self) {
+        mem::forget(self);
+    }
+}
+
+impl<'a, const N: usize> Drop for FieldGuard<'a, N> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: Only the first `self.len` entries of `self.fields` were ever written, by `write`.
+        let fields = unsafe { self.fields.get_unchecked(..self.len) };
+
+        for field in fields {
+            // SAFETY: `write` only ever records entries describing fields it itself initialized.
+            let (offset, drop_fn) = unsafe { field.assume_init_read() };
+
+            // SAFETY: `write` guaranteed `offset` was in-bounds and properly aligned for
+            //         the type erased into `drop_fn`, and that this field was never
+            //         previously recorded as dropped.
+            unsafe { drop_fn(self.base.add(offset)) };
+        }
+    }
+}
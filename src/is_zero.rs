@@ -0,0 +1,154 @@
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::ptr::NonNull;
+
+/// Marker trait for types whose all-zero-bytes representation is a valid,
+/// well-defined instance of `Self`.
+///
+/// This lets [`Vec`](crate::Vec) and friends skip per-element initialization
+/// in favor of a single zeroing allocation (mirroring `RawVec`'s
+/// `AllocInit::Zeroed` fast path) whenever the fill value happens to be zero.
+///
+/// # Safety
+///
+/// Implementors must guarantee that the all-zero-bytes bit pattern is a
+/// valid instance of `Self`, and that [`IsZero::is_zero`] returns `true` for
+/// a value if and only if it is that all-zero-bytes value. Getting this
+/// wrong lets safe code observe a value through the zeroed-allocation fast
+/// path that doesn't match what a real clone of the original would have
+/// produced.
+pub unsafe trait IsZero {
+    /// Returns whether `self` is the all-zero-bytes value of this type.
+    #[must_use]
+    fn is_zero(&self) -> bool;
+}
+
+/// Returns `value.is_zero()` if `T: `[`IsZero`], or `false` otherwise.
+///
+/// Stable Rust has no real specialization, so this leans on the "autoref
+/// specialization" trick: inherent methods are preferred over trait-default
+/// methods during method resolution, so `Spec<T>`'s inherent
+/// `__spec_is_zero` (only defined when `T: IsZero`) shadows the blanket
+/// trait's default whenever it's available.
+#[inline(always)]
+pub(crate) fn is_zero_value<T>(value: &T) -> bool {
+    trait FallbackIsZero {
+        #[inline(always)]
+        fn __spec_is_zero(&self) -> bool {
+            false
+        }
+    }
+
+    impl<T> FallbackIsZero for Spec<'_, T> {}
+
+    struct Spec<'a, T>(&'a T);
+
+    impl<T: IsZero> Spec<'_, T> {
+        #[inline(always)]
+        fn __spec_is_zero(&self) -> bool {
+            self.0.is_zero()
+        }
+    }
+
+    Spec(value).__spec_is_zero()
+}
+
+macro_rules! impl_is_zero_via_eq {
+    ($($ty:ty => $zero:expr),* $(,)?) => {
+        $(
+            unsafe impl IsZero for $ty {
+                #[inline(always)]
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+        )*
+    };
+}
+
+impl_is_zero_via_eq!(
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    bool => false, char => '\0',
+);
+
+// `-0.0`'s sign bit is set, so its bytes aren't all-zero; only `+0.0` qualifies.
+unsafe impl IsZero for f32 {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.to_bits() == 0
+    }
+}
+
+unsafe impl IsZero for f64 {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.to_bits() == 0
+    }
+}
+
+macro_rules! impl_is_zero_for_option_nonzero {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            // `Option<NonZero*>` is guaranteed to use the all-zero-bytes
+            // pattern to represent `None` (the niche optimization).
+            unsafe impl IsZero for Option<$ty> {
+                #[inline(always)]
+                fn is_zero(&self) -> bool {
+                    self.is_none()
+                }
+            }
+        )*
+    };
+}
+
+impl_is_zero_for_option_nonzero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize,
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+);
+
+// `Option<NonNull<T>>` also uses the all-zero-bytes pattern to represent
+// `None` (the niche optimization), same as `Option<NonZero*>` above.
+unsafe impl<T: Sized> IsZero for Option<NonNull<T>> {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.is_none()
+    }
+}
+
+unsafe impl<T: IsZero, const N: usize> IsZero for [T; N] {
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.iter().all(IsZero::is_zero)
+    }
+}
+
+macro_rules! impl_is_zero_for_tuple {
+    ($($name:ident),+) => {
+        unsafe impl<$($name: IsZero),+> IsZero for ($($name,)+) {
+            #[inline(always)]
+            fn is_zero(&self) -> bool {
+                #[allow(non_snake_case)]
+                let ($($name,)+) = self;
+                $($name.is_zero())&&+
+            }
+        }
+    };
+}
+
+impl_is_zero_for_tuple!(A);
+impl_is_zero_for_tuple!(A, B);
+impl_is_zero_for_tuple!(A, B, C);
+impl_is_zero_for_tuple!(A, B, C, D);
@@ -1,5 +1,9 @@
 #![no_std]
 #![allow(unused_unsafe)]
+#![cfg_attr(
+    feature = "unsize",
+    feature(unsize, coerce_unsized, dispatch_from_dyn)
+)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
@@ -11,16 +15,46 @@ mod owned;
 pub use owned::Owned;
 
 mod uninit;
-pub use uninit::Uninit;
+pub use uninit::{uninit_array, Uninit};
 
 mod init;
 pub use init::Init;
 
+mod field_guard;
+pub use field_guard::FieldGuard;
+
+mod tagged;
+pub use tagged::Tagged;
+
 mod vec_impl;
 
+mod util;
+
+mod try_clone;
+pub use try_clone::TryClone;
+
+mod is_zero;
+pub use is_zero::IsZero;
+
 mod slice_vec;
 pub use slice_vec::SliceVec;
 
+mod storage;
+pub use storage::Storage;
+#[cfg(feature = "alloc")]
+pub use storage::AllocStorage;
+
+mod array_vec;
+pub use array_vec::ArrayVec;
+
+#[cfg(feature = "alloc")]
+mod vec;
+#[cfg(feature = "alloc")]
+pub use vec::Vec;
+
+#[cfg(feature = "alloc")]
+mod macros;
+
 pub mod error;
 
 /// Assert that a condition is always true, helping to hint to the optimizer.
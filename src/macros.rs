@@ -0,0 +1,24 @@
+/// Construct a [`Vec`](crate::Vec), allocating through the heap [`Storage`](crate::Storage),
+/// returning a [`Result<Vec<T>, TryReserveError>`](crate::error::TryReserveError) instead of
+/// panicking on allocation failure.
+///
+/// Supports the same two forms as the standard library's `vec!`:
+///
+/// ```ignore
+/// let a: Result<Vec<i32>, _> = try_vec![1, 2, 3];
+/// let b: Result<Vec<i32>, _> = try_vec![0; 16];
+/// ```
+///
+/// The repeat form requires `T: `[`TryClone`](crate::TryClone) and
+/// bulk-reserves `n` slots up front. If a later reservation or clone fails
+/// partway through, every element inserted so far is torn down before the
+/// error is returned.
+#[macro_export]
+macro_rules! try_vec {
+    ($elem:expr; $n:expr) => {
+        $crate::Vec::__try_vec_from_elem($elem, $n)
+    };
+    ($($x:expr),* $(,)?) => {
+        $crate::Vec::__try_vec_from_array([$($x),*])
+    };
+}
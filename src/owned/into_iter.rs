@@ -0,0 +1,150 @@
+use core::{iter::FusedIterator, marker::PhantomData, mem::MaybeUninit, ptr, slice};
+
+use super::Owned;
+
+/// An owning, draining iterator over the elements of an `Owned<'a, [T]>`,
+/// created by its [`into_iter`](Owned::into_iter) method.
+///
+/// Dropping this iterator before it's exhausted still drops the remaining,
+/// not-yet-yielded elements.
+pub struct IntoIter<'a, T> {
+    base: *mut T,
+    total_len: usize,
+    begin: usize,
+    end: usize,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T> IntoIter<'a, T> {
+    #[inline]
+    fn new(base: *mut T, total_len: usize) -> IntoIter<'a, T> {
+        IntoIter {
+            base,
+            total_len,
+            begin: 0,
+            end: total_len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return a slice over the elements not yet yielded.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `begin..end` always covers exactly the not-yet-yielded,
+        //         still-initialized elements.
+        unsafe { slice::from_raw_parts(self.base.add(self.begin), self.end - self.begin) }
+    }
+
+    /// Consume this iterator, recovering the original backing slice as
+    /// potentially-uninitialized.
+    ///
+    /// Mirrors [`Owned::into_uninit`]: this does not drop anything, so any
+    /// elements not yet yielded are leaked rather than dropped.
+    #[inline]
+    #[must_use]
+    pub fn into_uninit(self) -> Owned<'a, [MaybeUninit<T>]> {
+        let this = core::mem::ManuallyDrop::new(self);
+
+        let slice = ptr::slice_from_raw_parts_mut(this.base.cast::<MaybeUninit<T>>(), this.total_len);
+
+        // SAFETY: `slice` spans exactly the original backing allocation this iterator
+        //         was created from, which remains valid for `'a` no matter how much
+        //         of it has been yielded or dropped.
+        unsafe { Owned::from_raw(slice) }
+    }
+}
+
+impl<'a, T> Iterator for IntoIter<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.begin == self.end {
+            return None;
+        }
+
+        // SAFETY: `begin` is in-bounds and has not been yielded before.
+        let item = unsafe { ptr::read(self.base.add(self.begin)) };
+        self.begin += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.begin;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IntoIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.begin == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: `end` is in-bounds and has not been yielded before.
+        Some(unsafe { ptr::read(self.base.add(self.end)) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IntoIter<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.begin
+    }
+}
+
+impl<'a, T> FusedIterator for IntoIter<'a, T> {}
+
+impl<'a, T> Drop for IntoIter<'a, T> {
+    fn drop(&mut self) {
+        // Drop whatever elements weren't yielded by the user.
+        //
+        // SAFETY: `begin..end` always covers exactly the not-yet-yielded,
+        //         still-initialized elements.
+        unsafe {
+            ptr::drop_in_place(slice::from_raw_parts_mut(
+                self.base.add(self.begin),
+                self.end - self.begin,
+            ))
+        };
+    }
+}
+
+impl<'a, T> Owned<'a, [T]> {
+    /// Consume this `Owned` slice, returning an owning, draining iterator
+    /// over its elements.
+    ///
+    /// This is a dedicated method rather than an [`IntoIterator`] impl: the
+    /// blanket `impl<T: ?Sized + Iterator> Iterator for Owned<'a, T>` above
+    /// means rustc can't rule out `Owned<'a, [T]>` someday satisfying that
+    /// bound, so implementing `IntoIterator` here would conflict with core's
+    /// blanket `impl<I: Iterator> IntoIterator for I`.
+    #[inline]
+    #[must_use]
+    pub fn into_iter(self) -> IntoIter<'a, T> {
+        let len = self.len();
+        let ptr = Owned::into_raw(self).cast::<T>();
+
+        IntoIter::new(ptr, len)
+    }
+}
+
+impl<'a, T, const N: usize> Owned<'a, [T; N]> {
+    /// Consume this `Owned` array, returning an owning, draining iterator
+    /// over its elements.
+    ///
+    /// This is a dedicated method rather than an [`IntoIterator`] impl; see
+    /// `Owned<[T]>::into_iter`'s docs for why.
+    #[inline]
+    #[must_use]
+    pub fn into_iter(self) -> IntoIter<'a, T> {
+        let ptr = Owned::into_raw(self).cast::<T>();
+
+        IntoIter::new(ptr, N)
+    }
+}
@@ -8,11 +8,14 @@ use core::{
     mem::ManuallyDrop,
     ops::{Deref, DerefMut},
     pin::Pin,
-    ptr::NonNull,
+    ptr::{self, NonNull},
 };
 
 use crate::{Init, Uninit};
 
+mod into_iter;
+pub use into_iter::IntoIter;
+
 /// An owned pointer to a `T` that does not own the underlying memory.
 ///
 /// # Drops
@@ -99,6 +102,78 @@ impl<'a, T: 'a> Owned<'a, T> {
     }
 }
 
+impl<'a, T: 'a> Owned<'a, [T]> {
+    /// Split this owned slice in two at `mid`, each half independently
+    /// owning (and dropping) its own elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > this.len()`.
+    #[inline]
+    #[must_use]
+    pub fn split_at(this: Owned<'a, [T]>, mid: usize) -> (Owned<'a, [T]>, Owned<'a, [T]>) {
+        let len = this.len();
+        assert!(mid <= len, "mid is out of bounds");
+
+        let ptr = Owned::into_raw(this).cast::<T>();
+
+        let left = ptr::slice_from_raw_parts_mut(ptr, mid);
+        // SAFETY: `mid <= len`, so `ptr.add(mid)` lies within (or at the end of) the
+        //         original allocation.
+        let right = ptr::slice_from_raw_parts_mut(unsafe { ptr.add(mid) }, len - mid);
+
+        // SAFETY: `left` and `right` are disjoint, in-bounds sub-slices of the
+        //         original, initialized allocation.
+        unsafe { (Owned::from_raw(left), Owned::from_raw(right)) }
+    }
+
+    /// Split off the first element, independently owning it from the rest of
+    /// the slice.
+    ///
+    /// Returns `None` if this slice is empty.
+    #[inline]
+    #[must_use]
+    pub fn split_first(this: Owned<'a, [T]>) -> Option<(Owned<'a, T>, Owned<'a, [T]>)> {
+        let len = this.len();
+
+        if len == 0 {
+            return None;
+        }
+
+        let ptr = Owned::into_raw(this).cast::<T>();
+
+        // SAFETY: `len != 0`, so `ptr.add(1)` lies within (or at the end of) the
+        //         original allocation.
+        let rest = ptr::slice_from_raw_parts_mut(unsafe { ptr.add(1) }, len - 1);
+
+        // SAFETY: `ptr` is the first element, and `rest` the remaining, disjoint
+        //         sub-slice, of the original, initialized allocation.
+        Some(unsafe { (Owned::from_raw(ptr), Owned::from_raw(rest)) })
+    }
+
+    /// Split off the last element, independently owning it from the rest of
+    /// the slice.
+    ///
+    /// Returns `None` if this slice is empty.
+    #[inline]
+    #[must_use]
+    pub fn split_last(this: Owned<'a, [T]>) -> Option<(Owned<'a, T>, Owned<'a, [T]>)> {
+        let len = this.len();
+
+        if len == 0 {
+            return None;
+        }
+
+        let ptr = Owned::into_raw(this).cast::<T>();
+
+        let init = ptr::slice_from_raw_parts_mut(ptr, len - 1);
+
+        // SAFETY: `ptr.add(len - 1)` is the last element, and `init` the remaining,
+        //         disjoint sub-slice, of the original, initialized allocation.
+        Some(unsafe { (Owned::from_raw(ptr.add(len - 1)), Owned::from_raw(init)) })
+    }
+}
+
 impl<'a, T: 'a + ?Sized + Init> Owned<'a, T> {
     /// Create an [`Owned`] from a mutable reference to an initialized `T`.
     ///
@@ -157,6 +232,43 @@ impl<'a, T: 'a + ?Sized + Init> Owned<'a, T> {
         // SAFETY: `ptr` is a valid pointer to an uninitialized `T`.
         unsafe { Owned::from_raw(ptr) }
     }
+
+    /// Attempt to in-place initialize an [`Owned`] to an uninitialized `T`.
+    ///
+    /// `f` is handed a mutable reference to the uninitialized `T` and must
+    /// fully initialize it before returning `Ok`. On `Ok`, the memory is
+    /// assumed initialized and handed back as `Owned<'a, T>`. On `Err`, the
+    /// memory is handed back still-uninitialized, alongside `f`'s error, so
+    /// the caller can reuse or discard it; nothing is dropped, since `f`
+    /// never produced a valid `T`.
+    ///
+    /// For piecewise initialization of a struct, where `f` may bail out
+    /// after writing only some of the fields, see
+    /// [`FieldGuard`](crate::FieldGuard) to ensure exactly those fields are
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// If `f` panics, any fields written through a
+    /// [`FieldGuard`](crate::FieldGuard) that hasn't been
+    /// [forgotten](crate::FieldGuard::forget) yet are dropped, but the panic
+    /// itself still propagates to the caller; `uninit`'s memory is leaked in
+    /// that case, just as it would be on a panic out of any other
+    /// `Owned`-consuming function.
+    #[inline]
+    pub fn try_init<E, F>(
+        mut uninit: Owned<'a, T::Uninit>,
+        f: F,
+    ) -> Result<Owned<'a, T>, (E, Owned<'a, T::Uninit>)>
+    where
+        F: FnOnce(&mut T::Uninit) -> Result<(), E>,
+    {
+        match f(&mut uninit) {
+            // SAFETY: `f`'s contract requires it to have fully initialized `uninit` to return `Ok`.
+            Ok(()) => Ok(unsafe { Owned::assume_init(uninit) }),
+            Err(e) => Err((e, uninit)),
+        }
+    }
 }
 
 impl<'a, T: 'a + ?Sized + Uninit> Owned<'a, T> {
@@ -356,6 +468,98 @@ impl<'a, T: 'a + ?Sized + Hasher> Hasher for Owned<'a, T> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<'a, T: 'a + ?Sized + std::io::Read> std::io::Read for Owned<'a, T> {
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.deref_mut().read(buf)
+    }
+
+    #[inline(always)]
+    fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> std::io::Result<usize> {
+        self.deref_mut().read_vectored(bufs)
+    }
+
+    #[inline(always)]
+    fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+        self.deref_mut().read_to_end(buf)
+    }
+
+    #[inline(always)]
+    fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+        self.deref_mut().read_to_string(buf)
+    }
+
+    #[inline(always)]
+    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()> {
+        self.deref_mut().read_exact(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: 'a + ?Sized + std::io::Write> std::io::Write for Owned<'a, T> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.deref_mut().write(buf)
+    }
+
+    #[inline(always)]
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        self.deref_mut().write_vectored(bufs)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.deref_mut().flush()
+    }
+
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.deref_mut().write_all(buf)
+    }
+
+    #[inline(always)]
+    fn write_fmt(&mut self, fmt: core::fmt::Arguments<'_>) -> std::io::Result<()> {
+        self.deref_mut().write_fmt(fmt)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: 'a + ?Sized + std::io::Seek> std::io::Seek for Owned<'a, T> {
+    #[inline(always)]
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.deref_mut().seek(pos)
+    }
+
+    #[inline(always)]
+    fn stream_position(&mut self) -> std::io::Result<u64> {
+        self.deref_mut().stream_position()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, T: 'a + ?Sized + std::io::BufRead> std::io::BufRead for Owned<'a, T> {
+    #[inline(always)]
+    fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+        self.deref_mut().fill_buf()
+    }
+
+    #[inline(always)]
+    fn consume(&mut self, amt: usize) {
+        self.deref_mut().consume(amt)
+    }
+
+    #[inline(always)]
+    fn read_until(&mut self, byte: u8, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize> {
+        self.deref_mut().read_until(byte, buf)
+    }
+
+    #[inline(always)]
+    fn read_line(&mut self, buf: &mut std::string::String) -> std::io::Result<usize> {
+        self.deref_mut().read_line(buf)
+    }
+}
+
 impl<'a, T: 'a + ?Sized + Iterator> Iterator for Owned<'a, T> {
     type Item = T::Item;
 
@@ -469,3 +673,15 @@ impl<'a, T: 'a + ?Sized> Unpin for Owned<'a, T> {}
 
 unsafe impl<'a, T: 'a + ?Sized> Send for Owned<'a, T> where &'a mut T: Send {}
 unsafe impl<'a, T: 'a + ?Sized> Sync for Owned<'a, T> where &'a mut T: Sync {}
+
+#[cfg(feature = "unsize")]
+impl<'a, T: 'a + ?Sized + core::marker::Unsize<U>, U: 'a + ?Sized>
+    core::ops::CoerceUnsized<Owned<'a, U>> for Owned<'a, T>
+{
+}
+
+#[cfg(feature = "unsize")]
+impl<'a, T: 'a + ?Sized + core::marker::Unsize<U>, U: 'a + ?Sized>
+    core::ops::DispatchFromDyn<Owned<'a, U>> for Owned<'a, T>
+{
+}
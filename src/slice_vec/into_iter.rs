@@ -0,0 +1,108 @@
+use core::{iter::FusedIterator, marker::PhantomData, ptr, slice};
+
+use super::SliceVec;
+
+/// An owning, draining iterator over the elements of a [`SliceVec`], created
+/// by its [`IntoIterator`] implementation.
+///
+/// Dropping this iterator before it's exhausted still drops the remaining,
+/// not-yet-yielded elements.
+pub struct IntoIter<'a, T> {
+    base: *mut T,
+    begin: usize,
+    end: usize,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T> IntoIter<'a, T> {
+    #[inline]
+    fn new(base: *mut T, len: usize) -> IntoIter<'a, T> {
+        IntoIter {
+            base,
+            begin: 0,
+            end: len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Return a slice over the elements not yet yielded.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: `begin..end` always covers exactly the not-yet-yielded,
+        //         still-initialized elements.
+        unsafe { slice::from_raw_parts(self.base.add(self.begin), self.end - self.begin) }
+    }
+}
+
+impl<'a, T> Iterator for IntoIter<'a, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        if self.begin == self.end {
+            return None;
+        }
+
+        // SAFETY: `begin` is in-bounds and has not been yielded before.
+        let item = unsafe { ptr::read(self.base.add(self.begin)) };
+        self.begin += 1;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.begin;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IntoIter<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.begin == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: `end` is in-bounds and has not been yielded before.
+        Some(unsafe { ptr::read(self.base.add(self.end)) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IntoIter<'a, T> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.end - self.begin
+    }
+}
+
+impl<'a, T> FusedIterator for IntoIter<'a, T> {}
+
+impl<'a, T> Drop for IntoIter<'a, T> {
+    fn drop(&mut self) {
+        // Drop whatever elements weren't yielded by the user.
+        //
+        // SAFETY: `begin..end` always covers exactly the not-yet-yielded,
+        //         still-initialized elements.
+        unsafe {
+            ptr::drop_in_place(slice::from_raw_parts_mut(
+                self.base.add(self.begin),
+                self.end - self.begin,
+            ))
+        };
+    }
+}
+
+impl<'a, T> IntoIterator for SliceVec<'a, T> {
+    type Item = T;
+    type IntoIter = IntoIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<'a, T> {
+        let (buf, len) = self.into_raw_parts();
+
+        IntoIter::new(buf.as_mut_ptr().cast::<T>(), len)
+    }
+}
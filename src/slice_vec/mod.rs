@@ -0,0 +1,675 @@
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    hash::Hash,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+use crate::{error::TryReserveError, vec_impl::VecImpl, Init, Owned, Uninit};
+
+mod drain;
+pub use drain::Drain;
+
+mod into_iter;
+pub use into_iter::IntoIter;
+
+pub struct SliceVec<'a, T> {
+    buf: &'a mut [MaybeUninit<T>],
+    len: usize,
+}
+
+impl<'a, T> SliceVec<'a, T> {
+    /// Create a new [`SliceVec`] from an uninitialized slice.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(slice: &'a mut [MaybeUninit<T>]) -> SliceVec<'a, T> {
+        SliceVec { buf: slice, len: 0 }
+    }
+
+    /// Create a new [`SliceVec`] from an potentially uninitialized slice,
+    /// and a length.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure:
+    ///
+    /// - `slice` is initialized for the first `len` elements.
+    /// - `len` is less than or equal to the length of the slice.
+    #[inline(always)]
+    #[must_use]
+    pub const unsafe fn from_raw_parts(buf: &'a mut [MaybeUninit<T>], len: usize) -> SliceVec<'a, T> {
+        unsafe { SliceVec { buf, len } }
+    }
+
+    /// Decompose a [`SliceVec`] into its raw components: `(buffer, length)`.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_raw_parts(self) -> (&'a mut [MaybeUninit<T>], usize) {
+        let mut this = ManuallyDrop::new(self);
+
+        (mem::take(&mut this.buf), this.len)
+    }
+
+    /// Get the length of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        VecImpl::len(self)
+    }
+
+    /// Returns whether this vector is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        VecImpl::is_empty(self)
+    }
+
+    /// Get the capacity of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        VecImpl::capacity(self)
+    }
+
+    /// Get the remaining capacity of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        VecImpl::remaining(self)
+    }
+
+    /// Returns whether this vector is full.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Get a raw pointer to this vector's buffer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr(&self) -> *const T {
+        VecImpl::as_ptr(self)
+    }
+
+    /// Get a mutable raw pointer to this vector's buffer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr_mut(&mut self) -> *mut T {
+        VecImpl::as_ptr_mut(self)
+    }
+
+    /// Get a slice to the initialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        VecImpl::as_slice(self)
+    }
+
+    /// Get a mutable slice to the initialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        VecImpl::as_slice_mut(self)
+    }
+
+    /// Get a slice to the remaining uninitialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_remaining(&self) -> &[MaybeUninit<T>] {
+        VecImpl::as_remaining(self)
+    }
+
+    /// Get a mutable slice to the remaining uninitialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_remaining_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        VecImpl::as_remaining_mut(self)
+    }
+
+    /// Split this vector into its initialized slice, and remaining uninitialized slice.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_parts(&self) -> (&[T], &[MaybeUninit<T>]) {
+        let (init, uninit) = unsafe { self.buf.split_at_unchecked(self.len) };
+        let init = unsafe { init.assume_init_ref() };
+
+        (init, uninit)
+    }
+
+    /// Split this vector mutably into its initialized slice, and remaining uninitialized slice.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_parts_mut(&mut self) -> (&mut [T], &mut [MaybeUninit<T>]) {
+        let (init, uninit) = unsafe { self.buf.split_at_mut_unchecked(self.len) };
+        let init = unsafe { init.assume_init_mut() };
+
+        (init, uninit)
+    }
+
+    /// Split this vector into its initialized slice, and remaining uninitialized slice.
+    ///
+    /// This consumes `self` and the caller takes ownership of the sections of the vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn into_parts(self) -> (Owned<'a, [T]>, Owned<'a, [MaybeUninit<T>]>) {
+        let (buf, len) = self.into_raw_parts();
+
+        let (init, uninit) = unsafe { buf.split_at_mut_unchecked(len) };
+        let init = unsafe { init.assume_init_owned() };
+        let uninit = unsafe { uninit.as_uninit_mut().assume_init_owned() };
+
+        (init, uninit)
+    }
+
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        VecImpl::truncate(self, new_len)
+    }
+
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        VecImpl::clear(self)
+    }
+
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        VecImpl::set_len(self, new_len)
+    }
+
+    #[inline(always)]
+    pub unsafe fn push_unchecked(&mut self, item: T) {
+        unsafe { VecImpl::push_unchecked(self, item) }
+    }
+
+    #[inline(always)]
+    pub fn try_push(&mut self, item: T) -> Result<(), (T, TryReserveError)> {
+        VecImpl::try_push(self, item)
+    }
+
+    #[inline(always)]
+    #[track_caller]
+    pub fn push(&mut self, item: T) {
+        VecImpl::push(self, item)
+    }
+
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        VecImpl::try_reserve(self, additional)
+    }
+
+    #[inline(always)]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        VecImpl::try_reserve_exact(self, additional)
+    }
+
+    /// Remove and return the last element, or [`None`] if this vector is empty.
+    #[inline(always)]
+    pub fn pop(&mut self) -> Option<T> {
+        VecImpl::pop(self)
+    }
+
+    /// Try to insert an element at `index`, shifting every element after it
+    /// one slot to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`SliceVec::len`].
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, item: T) -> Result<(), (T, TryReserveError)> {
+        VecImpl::try_insert(self, index, item)
+    }
+
+    /// Insert an element at `index`, shifting every element after it one
+    /// slot to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than [`SliceVec::len`], or if this
+    /// vector is already full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, item: T) {
+        VecImpl::insert(self, index, item);
+    }
+
+    /// Remove and return the element at `index`, shifting every element
+    /// after it one slot to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        VecImpl::remove(self, index)
+    }
+
+    /// Remove and return the element at `index`, moving the last element
+    /// into its place instead of shifting the tail down.
+    ///
+    /// This does not preserve ordering, but is `O(1)` rather than `O(n)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        VecImpl::swap_remove(self, index)
+    }
+
+    /// Resize this vector in place so that its length is `new_len`,
+    /// producing each newly added element by calling `f`.
+    ///
+    /// If `new_len` is less than the current length, the vector is
+    /// truncated instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than [`SliceVec::capacity`].
+    #[track_caller]
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) {
+        VecImpl::resize_with(self, new_len, f);
+    }
+
+    /// Try to resize this vector in place so that its length is `new_len`,
+    /// filling any newly added slots by cloning `value`.
+    #[track_caller]
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), TryReserveError>
+    where
+        T: Clone,
+    {
+        VecImpl::try_resize(self, new_len, value)
+    }
+
+    /// Retain only the elements for which `f` returns `true`, dropping the
+    /// rest in place and preserving the order of what's kept.
+    ///
+    /// If `f` panics, the elements it already visited are left compacted and
+    /// the vector's length updated accordingly; nothing is leaked or
+    /// double-dropped.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.retain_mut(|item| f(item));
+    }
+
+    /// Like [`SliceVec::retain`], but `f` is given a mutable reference to
+    /// each element.
+    pub fn retain_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let original_len = self.len();
+        // Avoid exposing a half-compacted vector if `f` panics.
+        unsafe { self.set_len(0) };
+
+        struct BackshiftOnDrop<'v, 'a, T> {
+            vec: &'v mut SliceVec<'a, T>,
+            processed_len: usize,
+            deleted_cnt: usize,
+            original_len: usize,
+        }
+
+        impl<'v, 'a, T> Drop for BackshiftOnDrop<'v, 'a, T> {
+            fn drop(&mut self) {
+                if self.deleted_cnt > 0 {
+                    // SAFETY: the tail `processed_len..original_len` is still
+                    //         initialized and untouched; shift it down to
+                    //         directly follow the retained prefix.
+                    unsafe {
+                        ptr::copy(
+                            self.vec.as_ptr().add(self.processed_len),
+                            self.vec
+                                .as_ptr_mut()
+                                .add(self.processed_len - self.deleted_cnt),
+                            self.original_len - self.processed_len,
+                        );
+                    }
+                }
+
+                // SAFETY: every element in `0..original_len - deleted_cnt` is
+                //         initialized and in its final, compacted position.
+                unsafe {
+                    self.vec
+                        .set_len(self.original_len - self.deleted_cnt);
+                }
+            }
+        }
+
+        let mut g = BackshiftOnDrop {
+            vec: self,
+            processed_len: 0,
+            deleted_cnt: 0,
+            original_len,
+        };
+
+        while g.processed_len < original_len {
+            // SAFETY: `processed_len` is always within the original length,
+            //         and the vector's reported length is temporarily `0`,
+            //         so this slot is not otherwise observed.
+            let cur = unsafe { &mut *g.vec.as_ptr_mut().add(g.processed_len) };
+
+            let keep = f(cur);
+            g.processed_len += 1;
+
+            if !keep {
+                g.deleted_cnt += 1;
+
+                // SAFETY: `cur` is initialized, and we're discarding it.
+                unsafe { ptr::drop_in_place(cur) };
+            } else if g.deleted_cnt > 0 {
+                // SAFETY: the hole at `processed_len - 1 - deleted_cnt` was
+                //         created by a prior removal.
+                unsafe {
+                    let hole = g.vec.as_ptr_mut().add(g.processed_len - 1 - g.deleted_cnt);
+                    ptr::copy_nonoverlapping(cur, hole, 1);
+                }
+            }
+        }
+
+        drop(g);
+    }
+
+    /// Remove consecutive elements for which `same_bucket` returns `true`,
+    /// keeping the first element of each run.
+    ///
+    /// If `same_bucket` panics, the elements already scanned are left
+    /// compacted and the vector's length updated accordingly.
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        VecImpl::dedup_by(self, same_bucket);
+    }
+
+    /// Remove consecutive duplicate elements, keeping the first of each run.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        VecImpl::dedup(self);
+    }
+
+    /// Remove consecutive elements that map to the same key, keeping the
+    /// first of each run.
+    #[inline]
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        VecImpl::dedup_by_key(self, key);
+    }
+
+    /// Try to clone every element of `slice` onto the end of this vector.
+    #[track_caller]
+    pub fn try_extend_from_slice(&mut self, slice: &[T]) -> Result<(), TryReserveError>
+    where
+        T: Clone,
+    {
+        VecImpl::try_extend_from_slice(self, slice)
+    }
+
+    /// Clone every element of `slice` onto the end of this vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this vector doesn't have enough remaining capacity to hold
+    /// all of `slice`.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, slice: &[T])
+    where
+        T: Clone,
+    {
+        self.try_extend_from_slice(slice).unwrap();
+    }
+
+    /// Create an iterator which uses a closure to determine which elements to
+    /// remove, yielding those elements and leaving the rest compacted in
+    /// place.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the
+    /// not-yet-scanned tail is left in place, shifted back to directly follow
+    /// the already-compacted prefix, without invoking the predicate again.
+    pub fn extract_if<'s, F>(&'s mut self, pred: F) -> impl Iterator<Item = T> + 's + 'a
+    where
+        F: FnMut(&mut T) -> bool + 's,
+    {
+        VecImpl::extract_if(self, pred)
+    }
+}
+
+unsafe impl<T> VecImpl for SliceVec<'_, T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= self.capacity());
+        self.len = len;
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    #[inline(always)]
+    fn grow(&mut self, _: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::CapacityOverflow)
+    }
+
+    #[inline(always)]
+    fn grow_exact(&mut self, _: usize) -> Result<(), TryReserveError> {
+        Err(TryReserveError::CapacityOverflow)
+    }
+
+    #[inline(always)]
+    fn as_ptr(&self) -> *const Self::Item {
+        self.buf.as_ptr().cast()
+    }
+
+    #[inline(always)]
+    fn as_ptr_mut(&mut self) -> *mut Self::Item {
+        self.buf.as_mut_ptr().cast()
+    }
+}
+
+impl<T> Deref for SliceVec<'_, T> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T> DerefMut for SliceVec<'_, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_slice_mut()
+    }
+}
+
+impl<T> Borrow<[T]> for SliceVec<'_, T> {
+    #[inline(always)]
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> BorrowMut<[T]> for SliceVec<'_, T> {
+    #[inline(always)]
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> AsRef<[T]> for SliceVec<'_, T> {
+    #[inline(always)]
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T> AsMut<[T]> for SliceVec<'_, T> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+impl<T> Default for SliceVec<'_, T> {
+    #[inline(always)]
+    fn default() -> Self {
+        SliceVec::new(&mut [])
+    }
+}
+
+impl<T: Hash> Hash for SliceVec<'_, T> {
+    #[inline(always)]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.deref().hash(state)
+    }
+}
+
+impl<T: PartialEq> PartialEq for SliceVec<'_, T> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.deref().eq(other.deref())
+    }
+
+    #[inline(always)]
+    fn ne(&self, other: &Self) -> bool {
+        self.deref().ne(other.deref())
+    }
+}
+
+impl<T: Eq> Eq for SliceVec<'_, T> {}
+
+impl<T: PartialOrd> PartialOrd for SliceVec<'_, T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.deref().partial_cmp(other.deref())
+    }
+
+    #[inline(always)]
+    fn lt(&self, other: &Self) -> bool {
+        self.deref().lt(other.deref())
+    }
+
+    #[inline(always)]
+    fn le(&self, other: &Self) -> bool {
+        self.deref().le(other.deref())
+    }
+
+    #[inline(always)]
+    fn gt(&self, other: &Self) -> bool {
+        self.deref().gt(other.deref())
+    }
+
+    #[inline(always)]
+    fn ge(&self, other: &Self) -> bool {
+        self.deref().ge(other.deref())
+    }
+}
+
+impl<T: Ord> Ord for SliceVec<'_, T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.deref().cmp(other.deref())
+    }
+}
+
+impl<'b, T> IntoIterator for &'b SliceVec<'_, T> {
+    type Item = &'b T;
+    type IntoIter = slice::Iter<'b, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'b, T> IntoIterator for &'b mut SliceVec<'_, T> {
+    type Item = &'b mut T;
+    type IntoIter = slice::IterMut<'b, T>;
+
+    #[inline(always)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SliceVec<'_, T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+impl<T> Drop for SliceVec<'_, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        let elems: *mut [T] = self.as_slice_mut();
+
+        unsafe { elems.drop_in_place() }
+    }
+}
+
+impl fmt::Write for SliceVec<'_, u8> {
+    /// Write as many bytes of `s` as fit, failing instead of truncating if
+    /// the remaining capacity is too small.
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+
+        if bytes.len() > self.remaining() {
+            return Err(fmt::Error);
+        }
+
+        let len = self.len();
+
+        // SAFETY: just checked that `bytes` fits within the remaining capacity.
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), self.as_ptr_mut().add(len), bytes.len());
+            self.set_len(len + bytes.len());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for SliceVec<'_, u8> {
+    /// Write as many bytes of `buf` as fit, reporting how many were written
+    /// rather than failing when the remaining capacity runs out.
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = buf.len().min(self.remaining());
+        let len = self.len();
+
+        // SAFETY: `n` is clamped to the remaining capacity.
+        unsafe {
+            ptr::copy_nonoverlapping(buf.as_ptr(), self.as_ptr_mut().add(len), n);
+            self.set_len(len + n);
+        }
+
+        Ok(n)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
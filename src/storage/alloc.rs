@@ -0,0 +1,487 @@
+use core::{alloc::Layout, fmt, marker::PhantomData, mem, ptr::NonNull};
+
+use alloc::alloc as global;
+
+use super::Storage;
+use crate::error::TryReserveError;
+
+/// Error returned when an [`Allocator`] is unable to fulfil a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AllocError;
+
+impl fmt::Display for AllocError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllocError {}
+
+/// A minimal, stable-compatible allocator abstraction modeled on
+/// `allocator-api2`, used to back [`AllocStorage`].
+///
+/// # Safety
+///
+/// Implementors must uphold the same contract as the standard library's
+/// unstable `core::alloc::Allocator`: a memory block handed out by one of
+/// these methods must only ever be passed to the other methods of the
+/// *same* allocator (or a clone of it that shares the same backing heap),
+/// and must not be accessed after it has been deallocated or shrunk past
+/// its new size.
+pub unsafe trait Allocator {
+    /// Attempt to allocate a block of memory fitting `layout`.
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError>;
+
+    /// Attempt to allocate a zeroed block of memory fitting `layout`.
+    #[inline]
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = self.allocate(layout)?;
+
+        // SAFETY: `ptr` was just allocated and is valid for `ptr.len()` bytes.
+        unsafe { as_ptr(ptr).write_bytes(0, ptr.len()) };
+
+        Ok(ptr)
+    }
+
+    /// Deallocate a block of memory previously allocated via this allocator.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must denote a block of memory currently allocated via this allocator.
+    /// - `layout` must be the same layout that was used to allocate that block.
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout);
+
+    /// Grow a previously allocated block to fit `new_layout`.
+    ///
+    /// The default implementation falls back to allocate-then-copy, which
+    /// every [`Allocator`] gets for free without needing to support
+    /// in-place growth.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must denote a block of memory currently allocated via this allocator.
+    /// - `old_layout` must be the layout that was used to allocate that block.
+    /// - `new_layout.size()` must be greater than or equal to `old_layout.size()`.
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+
+        let new_ptr = self.allocate(new_layout)?;
+
+        // SAFETY: `ptr` is valid for `old_layout.size()` bytes, and `new_ptr` is
+        //         freshly allocated and at least that large.
+        unsafe {
+            core::ptr::copy_nonoverlapping(ptr.as_ptr(), as_ptr(new_ptr), old_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+
+        Ok(new_ptr)
+    }
+
+    /// Grow a previously allocated block to fit `new_layout`, zeroing the newly
+    /// allocated tail.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Allocator::grow`].
+    #[inline]
+    unsafe fn grow_zeroed(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+
+        let new_ptr = self.allocate_zeroed(new_layout)?;
+
+        // SAFETY: see `grow`.
+        unsafe {
+            core::ptr::copy_nonoverlapping(ptr.as_ptr(), as_ptr(new_ptr), old_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+
+        Ok(new_ptr)
+    }
+
+    /// Shrink a previously allocated block down to fit `new_layout`.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must denote a block of memory currently allocated via this allocator.
+    /// - `old_layout` must be the layout that was used to allocate that block.
+    /// - `new_layout.size()` must be less than or equal to `old_layout.size()`.
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() <= old_layout.size());
+
+        let new_ptr = self.allocate(new_layout)?;
+
+        // SAFETY: `new_layout.size()` is no larger than `old_layout.size()`, so
+        //         `ptr` is valid for the copy.
+        unsafe {
+            core::ptr::copy_nonoverlapping(ptr.as_ptr(), as_ptr(new_ptr), new_layout.size());
+            self.deallocate(ptr, old_layout);
+        }
+
+        Ok(new_ptr)
+    }
+}
+
+/// Convert an allocated `NonNull<[u8]>` into a thin `*mut u8`.
+#[inline(always)]
+fn as_ptr(ptr: NonNull<[u8]>) -> *mut u8 {
+    ptr.as_ptr() as *mut u8
+}
+
+/// The global heap allocator, forwarding to [`alloc::alloc`](mod@alloc).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Global;
+
+unsafe impl Allocator for Global {
+    #[inline]
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(layout_dangling(layout), 0));
+        }
+
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { global::alloc(layout) };
+        let ptr = NonNull::new(ptr).ok_or(AllocError)?;
+
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    #[inline]
+    fn allocate_zeroed(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            return Ok(NonNull::slice_from_raw_parts(layout_dangling(layout), 0));
+        }
+
+        // SAFETY: `layout` has a non-zero size.
+        let ptr = unsafe { global::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).ok_or(AllocError)?;
+
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    #[inline]
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() != 0 {
+            // SAFETY: The caller ensures `ptr`/`layout` match a prior allocation.
+            unsafe { global::dealloc(ptr.as_ptr(), layout) }
+        }
+    }
+
+    #[inline]
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() >= old_layout.size());
+
+        if old_layout.align() != new_layout.align() || old_layout.size() == 0 {
+            // SAFETY: Falls back to allocate-then-copy, which is always sound.
+            return unsafe { Allocator::grow(&Global, ptr, old_layout, new_layout) };
+        }
+
+        // SAFETY: `ptr`/`old_layout` match a prior allocation from this allocator,
+        //         and `new_layout` shares the same alignment.
+        let ptr = unsafe { global::realloc(ptr.as_ptr(), old_layout, new_layout.size()) };
+        let ptr = NonNull::new(ptr).ok_or(AllocError)?;
+
+        Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+    }
+
+    #[inline]
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocError> {
+        debug_assert!(new_layout.size() <= old_layout.size());
+
+        if new_layout.size() == 0 {
+            // SAFETY: `ptr`/`old_layout` match a prior allocation from this allocator.
+            unsafe { self.deallocate(ptr, old_layout) };
+            return Ok(NonNull::slice_from_raw_parts(layout_dangling(new_layout), 0));
+        }
+
+        if old_layout.align() != new_layout.align() {
+            // SAFETY: Falls back to allocate-then-copy, which is always sound.
+            return unsafe { Allocator::shrink(&Global, ptr, old_layout, new_layout) };
+        }
+
+        // SAFETY: see `grow`.
+        let ptr = unsafe { global::realloc(ptr.as_ptr(), old_layout, new_layout.size()) };
+        let ptr = NonNull::new(ptr).ok_or(AllocError)?;
+
+        Ok(NonNull::slice_from_raw_parts(ptr, new_layout.size()))
+    }
+}
+
+/// Return a dangling, well-aligned pointer for a zero-sized `layout`.
+#[inline(always)]
+fn layout_dangling(layout: Layout) -> NonNull<u8> {
+    // SAFETY: `layout.align()` is always a non-zero power of two.
+    unsafe { NonNull::new_unchecked(layout.align() as *mut u8) }
+}
+
+/// A [`Storage`] backed by an [`Allocator`], growing the heap allocation as needed.
+///
+/// This is the owning, growable counterpart to the fixed-size array and
+/// borrowed-slice [`Storage`] impls: it holds a [`NonNull<Item>`], a
+/// capacity, and the allocator used to manage that memory, so [`Vec`](crate::Vec)
+/// and friends can grow on the heap without requiring nightly's unstable
+/// `Allocator` trait.
+pub struct AllocStorage<Item, A: Allocator = Global> {
+    ptr: NonNull<Item>,
+    cap: usize,
+    alloc: A,
+    _marker: PhantomData<Item>,
+}
+
+impl<Item> AllocStorage<Item, Global> {
+    /// Create an empty [`AllocStorage`] backed by the [`Global`] allocator.
+    ///
+    /// This does not allocate.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<Item> Default for AllocStorage<Item, Global> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Item, A: Allocator> AllocStorage<Item, A> {
+    /// Create an empty [`AllocStorage`] backed by the given allocator.
+    ///
+    /// This does not allocate.
+    #[inline]
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        AllocStorage {
+            ptr: NonNull::dangling(),
+            cap: 0,
+            alloc,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get a reference to the allocator backing this storage.
+    #[inline]
+    #[must_use]
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// The layout of the `cap` elements currently allocated, if any.
+    #[inline]
+    fn current_layout(&self) -> Option<Layout> {
+        if self.cap == 0 || mem::size_of::<Item>() == 0 {
+            None
+        } else {
+            // SAFETY: `self.cap` elements were allocated with this layout already.
+            Some(unsafe { Layout::array::<Item>(self.cap).unwrap_unchecked() })
+        }
+    }
+}
+
+unsafe impl<Item, A: Allocator> Storage<Item> for AllocStorage<Item, A> {
+    #[inline(always)]
+    fn base_ptr(&self) -> *const Item {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn base_ptr_mut(&mut self) -> *mut Item {
+        self.ptr.as_ptr()
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        if mem::size_of::<Item>() == 0 {
+            usize::MAX
+        } else {
+            self.cap
+        }
+    }
+
+    #[inline]
+    fn grow(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_in(len, additional, false)
+    }
+
+    #[inline]
+    fn grow_exact(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_in(len, additional, true)
+    }
+
+    #[inline]
+    fn grow_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_zeroed_in(len, additional, false)
+    }
+
+    #[inline]
+    fn grow_exact_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_zeroed_in(len, additional, true)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&mut self) {
+        if let Some(layout) = self.current_layout() {
+            // SAFETY: `self.ptr`/`layout` denote the block we allocated.
+            unsafe { self.alloc.deallocate(self.ptr.cast(), layout) };
+        }
+    }
+}
+
+impl<Item, A: Allocator> AllocStorage<Item, A> {
+    /// Shared implementation for [`Storage::grow`] and [`Storage::grow_exact`].
+    ///
+    /// When `exact` is `false` this doubles the capacity (mirroring `RawVec`'s
+    /// amortized growth), clamped to a minimum non-zero starting capacity so
+    /// that tiny elements don't churn through reallocations one at a time.
+    /// When `exact` is `true`, the capacity grows to precisely
+    /// `len + additional`.
+    fn grow_in(
+        &mut self,
+        len: usize,
+        additional: usize,
+        exact: bool,
+    ) -> Result<(), TryReserveError> {
+        if mem::size_of::<Item>() == 0 {
+            // Zero-sized types never need to grow; capacity is effectively unbounded.
+            return Ok(());
+        }
+
+        let Some(required) = len.checked_add(additional) else {
+            return Err(TryReserveError::new::<Item>(len, additional));
+        };
+
+        let new_cap = if exact {
+            required
+        } else {
+            required
+                .max(self.cap.saturating_mul(2))
+                .max(min_capacity::<Item>())
+        };
+
+        let new_layout = match Layout::array::<Item>(new_cap) {
+            Ok(layout) => layout,
+            Err(_) => return Err(TryReserveError::CapacityOverflow),
+        };
+
+        let result = if let Some(old_layout) = self.current_layout() {
+            // SAFETY: `self.ptr`/`old_layout` denote the block we previously allocated,
+            //         and `new_layout` is at least as large.
+            unsafe { self.alloc.grow(self.ptr.cast(), old_layout, new_layout) }
+        } else {
+            self.alloc.allocate(new_layout)
+        };
+
+        match result {
+            Ok(ptr) => {
+                self.ptr = ptr.cast();
+                self.cap = new_cap;
+                Ok(())
+            }
+            Err(AllocError) => Err(TryReserveError::AllocError { layout: new_layout }),
+        }
+    }
+}
+
+impl<Item, A: Allocator> AllocStorage<Item, A> {
+    /// Shared implementation for [`Storage::grow_zeroed`] and
+    /// [`Storage::grow_exact_zeroed`].
+    ///
+    /// When there's no existing allocation, this goes straight to a single
+    /// zeroing allocation (the `calloc`-style fast path). Once a buffer is
+    /// already allocated there's no such shortcut: our capacity can already
+    /// exceed our length, and the allocator's own "grow zeroed" only
+    /// promises zeroed memory *beyond* the old allocation, not beyond `len`.
+    /// So the already-allocated case falls back to a plain grow followed by
+    /// zeroing exactly the freshly available tail.
+    fn grow_zeroed_in(
+        &mut self,
+        len: usize,
+        additional: usize,
+        exact: bool,
+    ) -> Result<(), TryReserveError> {
+        if mem::size_of::<Item>() == 0 {
+            return Ok(());
+        }
+
+        if self.cap != 0 {
+            self.grow_in(len, additional, exact)?;
+
+            // SAFETY: `grow_in` just ensured `len..self.cap` is in bounds.
+            unsafe { self.base_ptr_mut().add(len).write_bytes(0, self.cap - len) };
+
+            return Ok(());
+        }
+
+        let Some(required) = len.checked_add(additional) else {
+            return Err(TryReserveError::new::<Item>(len, additional));
+        };
+
+        let new_cap = if exact {
+            required
+        } else {
+            required.max(min_capacity::<Item>())
+        };
+
+        let new_layout = match Layout::array::<Item>(new_cap) {
+            Ok(layout) => layout,
+            Err(_) => return Err(TryReserveError::CapacityOverflow),
+        };
+
+        match self.alloc.allocate_zeroed(new_layout) {
+            Ok(ptr) => {
+                self.ptr = ptr.cast();
+                self.cap = new_cap;
+                Ok(())
+            }
+            Err(AllocError) => Err(TryReserveError::AllocError { layout: new_layout }),
+        }
+    }
+}
+
+/// A minimum, non-zero starting capacity so that tiny elements don't
+/// churn through reallocations one element at a time.
+#[inline(always)]
+fn min_capacity<Item>() -> usize {
+    let size = mem::size_of::<Item>();
+
+    if size == 1 {
+        8
+    } else if size <= 1024 {
+        4
+    } else {
+        1
+    }
+}
+
+unsafe impl<Item: Send, A: Allocator + Send> Send for AllocStorage<Item, A> {}
+unsafe impl<Item: Sync, A: Allocator + Sync> Sync for AllocStorage<Item, A> {}
@@ -1,8 +1,12 @@
-use core::slice;
-use std::mem::MaybeUninit;
+use core::{mem::MaybeUninit, slice};
 
 use crate::{error::TryReserveError, util::assert_unchecked};
 
+#[cfg(feature = "alloc")]
+pub mod alloc;
+#[cfg(feature = "alloc")]
+pub use alloc::AllocStorage;
+
 /// Trait to some contiguous buffer of `Item`s.
 pub unsafe trait Storage<Item> {
     /// Return a pointer to the base of this buffer.
@@ -37,6 +41,38 @@ pub unsafe trait Storage<Item> {
     /// This should only ever be called when we know we need to resize the buffer.
     fn grow_exact(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError>;
 
+    /// Attempt to grow the buffer, zero-initializing the newly available capacity.
+    ///
+    /// The default implementation falls back to [`Storage::grow`] followed by
+    /// zeroing the freshly available tail. Storages with direct access to a
+    /// zeroing allocator (like [`AllocStorage`]) can override this to
+    /// allocate pre-zeroed memory directly instead.
+    ///
+    /// This should only ever be called when we know we need to resize the buffer.
+    #[inline]
+    fn grow_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        self.grow(len, additional)?;
+
+        // SAFETY: `grow` just ensured `len..self.capacity()` is in bounds.
+        unsafe { self.base_ptr_mut().add(len).write_bytes(0, self.capacity() - len) };
+
+        Ok(())
+    }
+
+    /// Attempt to grow the buffer by an exact amount, zero-initializing the
+    /// newly available capacity.
+    ///
+    /// This should only ever be called when we know we need to resize the buffer.
+    #[inline]
+    fn grow_exact_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        self.grow_exact(len, additional)?;
+
+        // SAFETY: `grow_exact` just ensured `len..self.capacity()` is in bounds.
+        unsafe { self.base_ptr_mut().add(len).write_bytes(0, self.capacity() - len) };
+
+        Ok(())
+    }
+
     /// Deallocate the memory stored within this buffer.
     unsafe fn dealloc(&mut self);
 
@@ -92,6 +128,42 @@ pub unsafe trait Storage<Item> {
 
         Ok(())
     }
+
+    /// Attempt to reserve additional memory, zero-initializing it.
+    #[must_use]
+    #[inline(always)]
+    fn try_reserve_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(len, additional) {
+            self.grow_zeroed(len, additional)?;
+        } else {
+            // SAFETY: `len..len + additional` lies within the already-allocated capacity.
+            unsafe { self.base_ptr_mut().add(len).write_bytes(0, additional) };
+        }
+
+        unsafe { assert_unchecked!(!self.needs_to_grow(len, additional)) };
+
+        Ok(())
+    }
+
+    /// Attempt to reserve an exact amount of additional memory, zero-initializing it.
+    #[must_use]
+    #[inline(always)]
+    fn try_reserve_exact_zeroed(
+        &mut self,
+        len: usize,
+        additional: usize,
+    ) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(len, additional) {
+            self.grow_exact_zeroed(len, additional)?;
+        } else {
+            // SAFETY: `len..len + additional` lies within the already-allocated capacity.
+            unsafe { self.base_ptr_mut().add(len).write_bytes(0, additional) };
+        }
+
+        unsafe { assert_unchecked!(!self.needs_to_grow(len, additional)) };
+
+        Ok(())
+    }
 }
 
 unsafe impl<T, S: Storage<T> + ?Sized> Storage<T> for &mut S {
@@ -120,6 +192,16 @@ unsafe impl<T, S: Storage<T> + ?Sized> Storage<T> for &mut S {
         S::grow_exact(self, len, additional)
     }
 
+    #[inline(always)]
+    fn grow_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        S::grow_zeroed(self, len, additional)
+    }
+
+    #[inline(always)]
+    fn grow_exact_zeroed(&mut self, len: usize, additional: usize) -> Result<(), TryReserveError> {
+        S::grow_exact_zeroed(self, len, additional)
+    }
+
     #[inline(always)]
     unsafe fn dealloc(&mut self) {
         unsafe { S::dealloc(self) }
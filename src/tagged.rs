@@ -0,0 +1,134 @@
+use core::{
+    marker::PhantomData,
+    mem::{self, ManuallyDrop},
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::Owned;
+
+/// An owned pointer to a `T`, with a small integer tag packed into the low
+/// bits of the pointer that [`mem::align_of::<T>()`](mem::align_of) guarantees
+/// are zero.
+///
+/// This is the marked-pointer technique used by lock-free reclamation
+/// schemes and intrusive data structures: attaching a discriminant or a
+/// "claimed" flag to a pointer without paying for an extra word of storage.
+///
+/// The number of usable tag bits is [`Tagged::TAG_BITS`]; a tag that doesn't
+/// fit is silently truncated by [`Tagged::compose`] and [`Tagged::set_tag`].
+///
+/// [`Deref`] and [`Drop`] always mask the tag off before touching the `T`.
+#[repr(transparent)]
+pub struct Tagged<'a, T> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Tagged<'a, T> {
+    /// The number of low bits of a `*mut T` guaranteed to be zero by
+    /// `T`'s alignment, and therefore usable to store a tag.
+    pub const TAG_BITS: u32 = mem::align_of::<T>().trailing_zeros();
+
+    /// The bitmask covering the usable tag bits.
+    const TAG_MASK: usize = (1usize << Self::TAG_BITS) - 1;
+
+    /// Compose an [`Owned`] and a tag into a [`Tagged`].
+    ///
+    /// `tag` is masked down to the low [`Tagged::TAG_BITS`] bits; any higher
+    /// bits are silently discarded.
+    #[inline]
+    #[must_use]
+    pub fn compose(owned: Owned<'a, T>, tag: usize) -> Tagged<'a, T> {
+        let ptr = Owned::into_raw(owned) as usize;
+        let tagged = (ptr & !Self::TAG_MASK) | (tag & Self::TAG_MASK);
+
+        Tagged {
+            // SAFETY: `ptr` came from a non-null `Owned`, and only its
+            //         guaranteed-zero low alignment bits were touched.
+            ptr: unsafe { NonNull::new_unchecked(tagged as *mut T) },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the current tag.
+    #[inline]
+    #[must_use]
+    pub fn tag(&self) -> usize {
+        (self.ptr.as_ptr() as usize) & Self::TAG_MASK
+    }
+
+    /// Set the tag, masking it down to the low [`Tagged::TAG_BITS`] bits.
+    #[inline]
+    pub fn set_tag(&mut self, tag: usize) {
+        let tagged = self.untagged_addr() | (tag & Self::TAG_MASK);
+
+        // SAFETY: We only ever touch the guaranteed-zero low alignment bits.
+        self.ptr = unsafe { NonNull::new_unchecked(tagged as *mut T) };
+    }
+
+    /// Get a reference to the underlying `T` and its current tag.
+    #[inline]
+    #[must_use]
+    pub fn decompose(&self) -> (&T, usize) {
+        let tag = self.tag();
+        (self.deref(), tag)
+    }
+
+    /// Get a mutable reference to the underlying `T` and its current tag.
+    #[inline]
+    #[must_use]
+    pub fn decompose_mut(&mut self) -> (&mut T, usize) {
+        let tag = self.tag();
+        (self.deref_mut(), tag)
+    }
+
+    /// Discard the tag, recovering the [`Owned`].
+    #[inline]
+    #[must_use]
+    pub fn into_owned(self) -> Owned<'a, T> {
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: `untagged_ptr` strips the tag back down to the original,
+        //         valid pointer that `compose` was handed.
+        unsafe { Owned::from_raw(this.untagged_ptr()) }
+    }
+
+    #[inline(always)]
+    fn untagged_addr(&self) -> usize {
+        (self.ptr.as_ptr() as usize) & !Self::TAG_MASK
+    }
+
+    #[inline(always)]
+    fn untagged_ptr(&self) -> *mut T {
+        self.untagged_addr() as *mut T
+    }
+}
+
+impl<'a, T> Deref for Tagged<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: `untagged_ptr` is always the valid, initialized `T` for `'a`
+        //         that the tag was packed onto.
+        unsafe { &*self.untagged_ptr() }
+    }
+}
+
+impl<'a, T> DerefMut for Tagged<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: See `deref`.
+        unsafe { &mut *self.untagged_ptr() }
+    }
+}
+
+impl<'a, T> Drop for Tagged<'a, T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: `untagged_ptr` is always the valid, initialized `T` for `'a`
+        //         that the tag was packed onto.
+        unsafe { self.untagged_ptr().drop_in_place() }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::error::TryReserveError;
+
+/// Trait for types that can be fallibly duplicated.
+///
+/// Unlike [`Clone`], which aborts the process (or panics) when an
+/// allocation it needs fails, [`TryClone::try_clone`] surfaces that failure
+/// as a [`TryReserveError`] so `no_std`/kernel-style callers can recover
+/// instead of risking an abort-on-OOM.
+pub trait TryClone: Sized {
+    /// Attempt to duplicate `self`, returning a [`TryReserveError`] if doing
+    /// so would require an allocation that failed.
+    fn try_clone(&self) -> Result<Self, TryReserveError>;
+}
+
+macro_rules! impl_try_clone_via_clone {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl TryClone for $ty {
+                /// This can never fail, as duplicating a
+                #[doc = concat!("[`", stringify!($ty), "`]")]
+                /// never allocates.
+                #[inline(always)]
+                fn try_clone(&self) -> Result<Self, TryReserveError> {
+                    Ok(Clone::clone(self))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_clone_via_clone!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char,
+);
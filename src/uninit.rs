@@ -15,6 +15,19 @@ mod sealed {
     impl<T> Sealed for [MaybeUninit<T>] {}
 }
 
+/// Create an uninitialized `[MaybeUninit<T>; N]`.
+///
+/// Unlike [`MaybeUninit::<T>::uninit`], this works for any `N` in a `const`
+/// context: an array of `MaybeUninit<T>` has no initialization invariant of
+/// its own, regardless of what `T` is.
+#[must_use]
+#[inline(always)]
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+    // SAFETY: A `MaybeUninit<[MaybeUninit<T>; N]>` does not require
+    //         initialization to be a valid `[MaybeUninit<T>; N]`.
+    unsafe { MaybeUninit::<[MaybeUninit<T>; N]>::uninit().assume_init() }
+}
+
 /// Trait for types that store potentially uninitialized data.
 pub trait Uninit: sealed::Sealed {
     type Init: Init<Uninit = Self> + ?Sized;
@@ -342,6 +355,50 @@ pub trait Uninit: sealed::Sealed {
         // SAFETY: We've initialized all elements without a panic.
         unsafe { self.assume_init_mut() }
     }
+
+    /// Initialize as many elements of [`Self::as_slice_mut`] as possible from
+    /// `iter`, stopping as soon as either `iter` is exhausted or every
+    /// element has been filled.
+    ///
+    /// Returns the initialized prefix; anything past it is left untouched
+    /// and uninitialized.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `iter`'s [`Iterator::next`] panics.
+    ///
+    /// Upon panicking, every element initialized so far is dropped.
+    #[must_use]
+    #[inline(always)]
+    fn init_from_iter<I>(&mut self, iter: I) -> &mut [<Self::Init as Init>::Sized]
+    where
+        I: IntoIterator<Item = <Self::Init as Init>::Sized>,
+    {
+        let mut guard = Guard {
+            slice: self.as_slice_mut(),
+            initialized: 0,
+        };
+
+        let mut iter = iter.into_iter();
+
+        while guard.initialized < guard.slice.len() {
+            match iter.next() {
+                Some(next) => {
+                    guard.slice[guard.initialized].write(next);
+                    guard.initialized += 1;
+                }
+                None => break,
+            }
+        }
+
+        let n = guard.initialized;
+        mem::forget(guard);
+
+        // SAFETY: We've just initialized the first `n` elements of `self`,
+        //         and the `Guard` that would otherwise drop them has been
+        //         forgotten.
+        unsafe { self.as_slice_mut()[..n].assume_init_mut() }
+    }
 }
 
 struct Guard<'a, T> {
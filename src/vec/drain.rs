@@ -0,0 +1,72 @@
+use core::{iter::FusedIterator, ops::RangeBounds};
+
+use super::Vec;
+use crate::{
+    storage::Storage,
+    vec_impl::{Drain as GenericDrain, VecImpl},
+};
+
+/// A draining iterator over a sub-range of a [`Vec`], created by [`Vec::drain`].
+///
+/// Dropping this iterator before it's exhausted still removes and drops the
+/// full requested range, shifting the unyielded tail back into place.
+pub struct Drain<'v, 'a, T, S: Storage<T>>(GenericDrain<'v, Vec<'a, T, S>>);
+
+impl<'v, 'a, T, S: Storage<T>> Drain<'v, 'a, T, S> {
+    /// Return a slice over the elements not yet yielded.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_slice()
+    }
+}
+
+impl<'a, T, S: Storage<T>> Vec<'a, T, S> {
+    /// Remove the given range from this vector, returning a draining iterator
+    /// over the removed elements.
+    ///
+    /// The vector's length is shortened to the start of the range up front
+    /// (leak-safe: if the returned [`Drain`] is leaked via [`core::mem::forget`]
+    /// rather than dropped, the drained-and-beyond elements simply stay
+    /// logically removed rather than being exposed twice), and the tail is
+    /// shifted back into place once the [`Drain`] is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is past the length of the vector.
+    #[track_caller]
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, 'a, T, S> {
+        Drain(VecImpl::drain(self, range))
+    }
+}
+
+impl<'v, 'a, T, S: Storage<T>> Iterator for Drain<'v, 'a, T, S> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'v, 'a, T, S: Storage<T>> DoubleEndedIterator for Drain<'v, 'a, T, S> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back()
+    }
+}
+
+impl<'v, 'a, T, S: Storage<T>> ExactSizeIterator for Drain<'v, 'a, T, S> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl<'v, 'a, T, S: Storage<T>> FusedIterator for Drain<'v, 'a, T, S> {}
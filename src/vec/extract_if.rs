@@ -0,0 +1,44 @@
+use super::Vec;
+use crate::{
+    storage::Storage,
+    vec_impl::{ExtractIf as GenericExtractIf, VecImpl},
+};
+
+/// A draining filter iterator over a [`Vec`], created by [`Vec::extract_if`].
+///
+/// Every element for which the predicate returns `true` is removed and
+/// yielded; the rest are compacted in place, preserving their order.
+/// Dropping this iterator before it's exhausted leaves the not-yet-scanned
+/// tail in place, shifted back to directly follow the already-compacted
+/// prefix, without invoking the predicate again.
+pub struct ExtractIf<'v, 'a, T, S: Storage<T>, F: FnMut(&mut T) -> bool>(
+    GenericExtractIf<'v, Vec<'a, T, S>, F>,
+);
+
+impl<'a, T, S: Storage<T>> Vec<'a, T, S> {
+    /// Create an iterator which uses a closure to determine which elements to
+    /// remove, yielding those elements and leaving the rest compacted in
+    /// place.
+    ///
+    /// If the returned [`ExtractIf`] is dropped before being fully consumed,
+    /// the not-yet-scanned tail is left in place, shifted back to directly
+    /// follow the already-compacted prefix, without invoking the predicate
+    /// again.
+    pub fn extract_if<F: FnMut(&mut T) -> bool>(&mut self, pred: F) -> ExtractIf<'_, 'a, T, S, F> {
+        ExtractIf(VecImpl::extract_if(self, pred))
+    }
+}
+
+impl<'v, 'a, T, S: Storage<T>, F: FnMut(&mut T) -> bool> Iterator for ExtractIf<'v, 'a, T, S, F> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
@@ -0,0 +1,515 @@
+use core::{marker::PhantomData, mem::MaybeUninit};
+
+use crate::{
+    error::TryReserveError,
+    is_zero::is_zero_value,
+    storage::{alloc::Allocator, AllocStorage, Storage},
+    vec_impl::VecImpl,
+    TryClone,
+};
+
+mod drain;
+pub use drain::Drain;
+
+mod extract_if;
+pub use extract_if::ExtractIf;
+
+/// A growable, owning vector generic over its backing [`Storage`].
+///
+/// Unlike [`SliceVec`](crate::SliceVec), which only ever borrows a fixed
+/// `&'a mut [MaybeUninit<T>]`, [`Vec`] can grow by delegating to whatever
+/// [`Storage`] it is parameterized over. By default that's [`AllocStorage`],
+/// giving an owning, heap-backed vector without requiring nightly's
+/// unstable `Allocator` trait; plugging in a borrowed slice or array
+/// storage instead yields a fixed-capacity vector, just like [`SliceVec`].
+pub struct Vec<'a, T, S: Storage<T> = AllocStorage<T>> {
+    storage: S,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Vec<'a, T, AllocStorage<T>> {
+    /// Create a new, empty [`Vec`] backed by the global allocator.
+    ///
+    /// This does not allocate until elements are pushed.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Vec {
+            storage: AllocStorage::new(),
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Default for Vec<'a, T, AllocStorage<T>> {
+    #[inline]
+    fn default() -> Self {
+        Vec::new()
+    }
+}
+
+impl<'a, T, S: Storage<T>> Vec<'a, T, S> {
+    /// Create a new [`Vec`] from an already-constructed, empty [`Storage`].
+    #[inline]
+    #[must_use]
+    pub fn with_storage(storage: S) -> Self {
+        Vec {
+            storage,
+            len: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Decompose this [`Vec`] into its backing storage and length.
+    #[inline]
+    #[must_use]
+    pub fn into_raw_parts(self) -> (S, usize) {
+        let this = core::mem::ManuallyDrop::new(self);
+
+        // SAFETY: `this.storage` is only read once here, and `this` is never
+        //         used again afterwards.
+        unsafe { (core::ptr::read(&this.storage), this.len) }
+    }
+
+    /// Get the length of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        VecImpl::len(self)
+    }
+
+    /// Returns whether this vector is empty.
+    #[inline(always)]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        VecImpl::is_empty(self)
+    }
+
+    /// Get the capacity of this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        VecImpl::capacity(self)
+    }
+
+    /// Get a raw pointer to this vector's buffer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr(&self) -> *const T {
+        VecImpl::as_ptr(self)
+    }
+
+    /// Get a mutable raw pointer to this vector's buffer.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_ptr_mut(&mut self) -> *mut T {
+        VecImpl::as_ptr_mut(self)
+    }
+
+    /// Get a slice to the initialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        VecImpl::as_slice(self)
+    }
+
+    /// Get a mutable slice to the initialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        VecImpl::as_slice_mut(self)
+    }
+
+    /// Get a slice to the remaining uninitialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_remaining(&self) -> &[MaybeUninit<T>] {
+        VecImpl::as_remaining(self)
+    }
+
+    /// Get a mutable slice to the remaining uninitialized elements in this vector.
+    #[inline(always)]
+    #[must_use]
+    pub fn as_remaining_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        VecImpl::as_remaining_mut(self)
+    }
+
+    /// Shortens the vector, keeping the first `new_len` elements and dropping the rest.
+    #[inline(always)]
+    pub fn truncate(&mut self, new_len: usize) {
+        VecImpl::truncate(self, new_len)
+    }
+
+    /// Clears the vector, dropping all elements.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        VecImpl::clear(self)
+    }
+
+    /// Set the length of this vector.
+    ///
+    /// # Safety
+    ///
+    /// See [`VecImpl::set_len`].
+    #[inline(always)]
+    pub unsafe fn set_len(&mut self, new_len: usize) {
+        unsafe { VecImpl::set_len(self, new_len) }
+    }
+
+    /// Push an element without checking that it will fit.
+    ///
+    /// # Safety
+    ///
+    /// See [`VecImpl::push_unchecked`].
+    #[inline(always)]
+    pub unsafe fn push_unchecked(&mut self, item: T) {
+        unsafe { VecImpl::push_unchecked(self, item) }
+    }
+
+    /// Pop the last element off of this vector.
+    #[inline(always)]
+    pub fn pop(&mut self) -> Option<T> {
+        VecImpl::pop(self)
+    }
+
+    /// Attempt to reserve capacity for at least `additional` more elements.
+    #[inline(always)]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        VecImpl::try_reserve(self, additional)
+    }
+
+    /// Attempt to reserve capacity for exactly `additional` more elements.
+    #[inline(always)]
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        VecImpl::try_reserve_exact(self, additional)
+    }
+
+    /// Reserve capacity for at least `additional` more elements.
+    #[inline(always)]
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        VecImpl::reserve(self, additional)
+    }
+
+    /// Attempt to push an element onto the end of this vector, returning the
+    /// element back to the caller if reserving space fails.
+    #[inline]
+    pub fn try_push_give_back(&mut self, item: T) -> Result<(), (T, TryReserveError)> {
+        VecImpl::try_push(self, item)
+    }
+
+    /// Attempt to push an element onto the end of this vector.
+    #[inline]
+    pub fn try_push(&mut self, item: T) -> Result<(), TryReserveError> {
+        self.try_push_give_back(item).map_err(|(_, error)| error)
+    }
+
+    /// Attempt to insert an element at `index`, shifting every element
+    /// after it one slot to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > self.len()`.
+    #[inline]
+    #[track_caller]
+    pub fn try_insert(&mut self, index: usize, item: T) -> Result<(), (T, TryReserveError)> {
+        let len = self.len();
+        assert!(index <= len, "insertion index out of bounds");
+
+        if let Err(error) = self.try_reserve(1) {
+            return Err((item, error));
+        }
+
+        // SAFETY: We just reserved space for one more element, and `index <= len`.
+        unsafe {
+            let ptr = self.as_ptr_mut().add(index);
+
+            if index < len {
+                core::ptr::copy(ptr, ptr.add(1), len - index);
+            }
+
+            ptr.write(item);
+            self.set_len(len + 1);
+        }
+
+        Ok(())
+    }
+
+    /// Attempt to move every element out of `other` and onto the end of `self`,
+    /// leaving `other` empty.
+    pub fn try_append<S2: Storage<T>>(
+        &mut self,
+        other: &mut Vec<'_, T, S2>,
+    ) -> Result<(), TryReserveError> {
+        let count = other.len();
+        self.try_reserve(count)?;
+
+        // SAFETY: We just reserved `count` additional slots, and `other` has
+        //         exactly `count` initialized elements starting at its base.
+        unsafe {
+            let len = self.len();
+            core::ptr::copy_nonoverlapping(other.as_ptr(), self.as_ptr_mut().add(len), count);
+
+            self.set_len(len + count);
+            other.set_len(0);
+        }
+
+        Ok(())
+    }
+
+    /// Attempt to extend this vector with the contents of an iterator.
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, iter: I) -> Result<(), TryReserveError> {
+        let iter = iter.into_iter();
+
+        let (lower, _) = iter.size_hint();
+        self.try_reserve(lower)?;
+
+        for item in iter {
+            self.try_push_give_back(item).map_err(|(_, error)| error)?;
+        }
+
+        Ok(())
+    }
+
+    /// Attempt to resize this vector so that its length is `new_len`, filling
+    /// any newly added slots by cloning `value`.
+    ///
+    /// If `value` happens to be the all-zero-bytes value of `T` (see
+    /// [`IsZero`](crate::IsZero)), the new slots are filled via a single zeroing allocation
+    /// instead of cloning `value` one element at a time.
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), TryReserveError>
+    where
+        T: Clone,
+    {
+        let len = self.len();
+
+        if new_len <= len {
+            self.truncate(new_len);
+            return Ok(());
+        }
+
+        let additional = new_len - len;
+
+        if is_zero_value(&value) {
+            self.storage.try_reserve_zeroed(len, additional)?;
+
+            // SAFETY: `len..new_len` was just zero-initialized, which is a
+            //         valid bit pattern for `T` because `is_zero_value`
+            //         returning `true` only happens when `T: IsZero` attests
+            //         to exactly that.
+            unsafe { self.set_len(new_len) };
+
+            return Ok(());
+        }
+
+        self.try_reserve(additional)?;
+
+        for _ in 0..additional - 1 {
+            // SAFETY: We just reserved `additional` slots for the remaining growth.
+            unsafe { self.push_unchecked(value.clone()) };
+        }
+
+        // SAFETY: See above; the final element consumes `value` instead of cloning it.
+        unsafe { self.push_unchecked(value) };
+
+        Ok(())
+    }
+
+    /// Resize this vector in place so that its length is `new_len`,
+    /// producing each newly added element by calling `f`.
+    ///
+    /// If `new_len` is less than the current length, the vector is
+    /// truncated instead.
+    #[track_caller]
+    pub fn resize_with<F: FnMut() -> T>(&mut self, new_len: usize, f: F) {
+        VecImpl::resize_with(self, new_len, f);
+    }
+
+    /// Try to clone every element of `slice` onto the end of this vector.
+    pub fn try_extend_from_slice(&mut self, slice: &[T]) -> Result<(), TryReserveError>
+    where
+        T: Clone,
+    {
+        VecImpl::try_extend_from_slice(self, slice)
+    }
+
+    /// Remove consecutive elements for which `same_bucket` returns `true`,
+    /// keeping the first element of each run.
+    ///
+    /// If `same_bucket` panics, the elements already scanned are left
+    /// compacted and the vector's length updated accordingly.
+    #[inline]
+    pub fn dedup_by<F>(&mut self, same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        VecImpl::dedup_by(self, same_bucket);
+    }
+
+    /// Remove consecutive duplicate elements, keeping the first of each run.
+    #[inline]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        VecImpl::dedup(self);
+    }
+
+    /// Remove consecutive elements that map to the same key, keeping the
+    /// first of each run.
+    #[inline]
+    pub fn dedup_by_key<K, F>(&mut self, key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq,
+    {
+        VecImpl::dedup_by_key(self, key);
+    }
+}
+
+unsafe impl<'a, T, S: Storage<T>> VecImpl for Vec<'a, T, S> {
+    type Item = T;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline(always)]
+    unsafe fn set_len(&mut self, len: usize) {
+        debug_assert!(len <= self.capacity());
+        self.len = len;
+    }
+
+    #[inline(always)]
+    fn capacity(&self) -> usize {
+        self.storage.capacity()
+    }
+
+    #[inline(always)]
+    fn grow(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.storage.grow(self.len, additional)
+    }
+
+    #[inline(always)]
+    fn grow_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.storage.grow_exact(self.len, additional)
+    }
+
+    #[inline(always)]
+    fn as_ptr(&self) -> *const T {
+        self.storage.base_ptr()
+    }
+
+    #[inline(always)]
+    fn as_ptr_mut(&mut self) -> *mut T {
+        self.storage.base_ptr_mut()
+    }
+}
+
+impl<'a, T, S: Storage<T>> core::ops::Deref for Vec<'a, T, S> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<'a, T, S: Storage<T>> core::ops::DerefMut for Vec<'a, T, S> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_slice_mut()
+    }
+}
+
+impl<'a, T, S: Storage<T>> Drop for Vec<'a, T, S> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        self.clear();
+
+        // SAFETY: No elements remain live after `clear`, and the storage is
+        //         never used again afterwards.
+        unsafe { self.storage.dealloc() }
+    }
+}
+
+impl<'a, T> Vec<'a, T, AllocStorage<T>> {
+    /// Build a [`Vec`] from a fixed-size array of elements.
+    ///
+    /// This is the list form of [`try_vec!`](crate::try_vec), not meant to be
+    /// called directly.
+    #[doc(hidden)]
+    pub fn __try_vec_from_array<const N: usize>(items: [T; N]) -> Result<Self, TryReserveError> {
+        let mut vec = Vec::new();
+        vec.try_reserve(N)?;
+
+        for item in items {
+            // SAFETY: We just reserved `N` slots.
+            unsafe { vec.push_unchecked(item) };
+        }
+
+        Ok(vec)
+    }
+
+    /// Build a [`Vec`] by cloning `elem` `n` times.
+    ///
+    /// This is the repeat form of [`try_vec!`](crate::try_vec), not meant to be
+    /// called directly.
+    ///
+    /// If `elem` happens to be the all-zero-bytes value of `T` (see
+    /// [`IsZero`](crate::IsZero)), the vector is filled via a single zeroing allocation
+    /// instead of cloning `elem` one element at a time.
+    #[doc(hidden)]
+    pub fn __try_vec_from_elem(elem: T, n: usize) -> Result<Self, TryReserveError>
+    where
+        T: TryClone,
+    {
+        if is_zero_value(&elem) {
+            let mut vec = Vec::new();
+            vec.storage.try_reserve_zeroed(0, n)?;
+
+            // SAFETY: See `try_resize`.
+            unsafe { vec.set_len(n) };
+
+            return Ok(vec);
+        }
+
+        let mut vec = Vec::new();
+        vec.try_reserve(n)?;
+
+        let Some(last) = n.checked_sub(1) else {
+            return Ok(vec);
+        };
+
+        for _ in 0..last {
+            // SAFETY: We just reserved `n` slots, and we've written fewer than `n` so far.
+            unsafe { vec.push_unchecked(elem.try_clone()?) };
+        }
+
+        // SAFETY: See above; this is the final slot, so `elem` is moved in directly.
+        unsafe { vec.push_unchecked(elem) };
+
+        Ok(vec)
+    }
+}
+
+impl<'a, T: TryClone, A: Allocator + Clone> TryClone for Vec<'a, T, AllocStorage<T, A>> {
+    fn try_clone(&self) -> Result<Self, TryReserveError> {
+        let mut storage = AllocStorage::new_in(self.storage.allocator().clone());
+        storage.try_reserve(0, self.len())?;
+
+        let mut new = Vec::with_storage(storage);
+
+        for item in self.as_slice() {
+            // Cloning element-by-element into the freshly reserved storage: if an
+            // element fails to clone partway through, `new` is dropped here and
+            // tears down everything cloned so far along with its storage.
+            unsafe { new.push_unchecked(item.try_clone()?) };
+        }
+
+        Ok(new)
+    }
+}
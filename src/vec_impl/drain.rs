@@ -0,0 +1,132 @@
+use core::{
+    iter::FusedIterator,
+    ops::{Bound, Range, RangeBounds},
+    ptr, slice,
+};
+
+use super::VecImpl;
+
+/// A draining iterator over a sub-range of any [`VecImpl`], created by
+/// [`VecImpl::drain`].
+///
+/// Dropping this iterator before it's exhausted still removes and drops the
+/// full requested range, shifting the unyielded tail back into place.
+pub(crate) struct Drain<'v, V: VecImpl + ?Sized> {
+    vec: &'v mut V,
+    /// Pointer to, and remaining length of, the not-yet-yielded drained elements.
+    iter: slice::Iter<'v, V::Item>,
+    /// The index (into `vec`'s original buffer) where the tail begins.
+    tail_start: usize,
+    /// The number of elements in the tail that must be shifted back into place.
+    tail_len: usize,
+}
+
+impl<'v, V: VecImpl + ?Sized> Drain<'v, V> {
+    #[track_caller]
+    pub(crate) fn new<R: RangeBounds<usize>>(vec: &'v mut V, range: R) -> Self {
+        let len = vec.len();
+        let Range { start, end } = simplify_range(range, len);
+
+        // SAFETY: `start..end` is within bounds of the initialized elements.
+        let drained = unsafe { slice::from_raw_parts(vec.as_ptr().add(start), end - start) };
+
+        // Shorten the vector up front so a leaked `Drain` can't expose the
+        // tail (or the drained range) as live elements.
+        unsafe { vec.set_len(start) };
+
+        Drain {
+            tail_start: end,
+            tail_len: len - end,
+            iter: drained.iter(),
+            vec,
+        }
+    }
+
+    /// Return a slice over the elements not yet yielded.
+    #[inline]
+    #[must_use]
+    pub(crate) fn as_slice(&self) -> &[V::Item] {
+        self.iter.as_slice()
+    }
+}
+
+#[track_caller]
+fn simplify_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n + 1,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n + 1,
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+
+    assert!(start <= end, "drain start is greater than its end");
+    assert!(end <= len, "drain end is out of bounds");
+
+    start..end
+}
+
+impl<'v, V: VecImpl + ?Sized> Iterator for Drain<'v, V> {
+    type Item = V::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<V::Item> {
+        self.iter
+            .next()
+            // SAFETY: Every element yielded by `iter` has not been yielded before,
+            //         and will not be dropped again until we explicitly drop it here.
+            .map(|item| unsafe { ptr::read(item) })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'v, V: VecImpl + ?Sized> DoubleEndedIterator for Drain<'v, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<V::Item> {
+        self.iter
+            .next_back()
+            // SAFETY: See `next`.
+            .map(|item| unsafe { ptr::read(item) })
+    }
+}
+
+impl<'v, V: VecImpl + ?Sized> ExactSizeIterator for Drain<'v, V> {
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'v, V: VecImpl + ?Sized> FusedIterator for Drain<'v, V> {}
+
+impl<'v, V: VecImpl + ?Sized> Drop for Drain<'v, V> {
+    fn drop(&mut self) {
+        // Drop whatever elements weren't yielded by the user.
+        //
+        // SAFETY: `self.iter.as_slice()` only ever covers not-yet-yielded,
+        //         initialized elements of the drained range.
+        unsafe { ptr::drop_in_place(self.iter.as_slice() as *const [V::Item] as *mut [V::Item]) };
+
+        if self.tail_len > 0 {
+            let start = self.vec.len();
+
+            // SAFETY: `start..start + tail_len` was the original tail of the
+            //         vector, still fully initialized; we shift it back to
+            //         immediately follow the retained prefix.
+            unsafe {
+                let ptr = self.vec.as_ptr_mut();
+                ptr::copy(ptr.add(self.tail_start), ptr.add(start), self.tail_len);
+
+                self.vec.set_len(start + self.tail_len);
+            }
+        }
+    }
+}
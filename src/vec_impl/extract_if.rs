@@ -0,0 +1,103 @@
+use core::{marker::PhantomData, ptr};
+
+use super::VecImpl;
+
+/// A draining filter iterator over any [`VecImpl`], created by
+/// [`VecImpl::extract_if`].
+///
+/// Every element for which the predicate returns `true` is removed and
+/// yielded; the rest are compacted in place, preserving their order.
+/// Dropping this iterator before it's exhausted leaves the not-yet-scanned
+/// tail in place, shifted back to directly follow the already-compacted
+/// prefix, without invoking the predicate again.
+pub(crate) struct ExtractIf<'v, V: VecImpl + ?Sized, F: FnMut(&mut V::Item) -> bool> {
+    vec: &'v mut V,
+    /// The index of the next element to examine.
+    idx: usize,
+    /// The number of elements removed so far.
+    del: usize,
+    /// The original length of the vector, before any elements were removed.
+    old_len: usize,
+    pred: F,
+    _marker: PhantomData<fn(&mut V::Item) -> bool>,
+}
+
+impl<'v, V: VecImpl + ?Sized, F: FnMut(&mut V::Item) -> bool> ExtractIf<'v, V, F> {
+    #[track_caller]
+    pub(crate) fn new(vec: &'v mut V, pred: F) -> Self {
+        let old_len = vec.len();
+
+        // Treat the vector as logically empty while we scan; `Drop` restores
+        // the true length once scanning finishes.
+        unsafe { vec.set_len(0) };
+
+        ExtractIf {
+            vec,
+            idx: 0,
+            del: 0,
+            old_len,
+            pred,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'v, V: VecImpl + ?Sized, F: FnMut(&mut V::Item) -> bool> Iterator for ExtractIf<'v, V, F> {
+    type Item = V::Item;
+
+    fn next(&mut self) -> Option<V::Item> {
+        while self.idx < self.old_len {
+            // SAFETY: `idx` is within the original length, and the vector's
+            //         reported length is temporarily `0`, so nothing else
+            //         observes or moves this slot while we hold `&mut`.
+            let cur = unsafe { &mut *self.vec.as_ptr_mut().add(self.idx) };
+
+            let matched = (self.pred)(cur);
+            self.idx += 1;
+
+            if matched {
+                self.del += 1;
+
+                // SAFETY: `cur` is initialized, and ownership is transferred to the caller.
+                return Some(unsafe { ptr::read(cur) });
+            } else if self.del > 0 {
+                // SAFETY: `idx - 1 - del` is a hole created by a prior removal.
+                unsafe {
+                    let hole = self.vec.as_ptr_mut().add(self.idx - 1 - self.del);
+                    ptr::copy_nonoverlapping(cur, hole, 1);
+                }
+            }
+        }
+
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.old_len - self.idx))
+    }
+}
+
+impl<'v, V: VecImpl + ?Sized, F: FnMut(&mut V::Item) -> bool> Drop for ExtractIf<'v, V, F> {
+    fn drop(&mut self) {
+        // Shift the unscanned tail back by `del` without re-invoking the
+        // predicate: if `next` panicked inside it, doing so here would call
+        // it again during unwind, and a second panic would abort the process.
+        if self.idx != self.old_len {
+            // SAFETY: `idx..old_len` is still initialized and untouched; shift
+            //         it down to directly follow the retained, compacted prefix.
+            unsafe {
+                let ptr = self.vec.as_ptr_mut();
+                ptr::copy(
+                    ptr.add(self.idx),
+                    ptr.add(self.idx - self.del),
+                    self.old_len - self.idx,
+                );
+            }
+        }
+
+        // SAFETY: Every element in `0..old_len - del` is initialized and in its
+        //         final, compacted position.
+        unsafe { self.vec.set_len(self.old_len - self.del) };
+    }
+}
@@ -0,0 +1,694 @@
+use core::{mem::MaybeUninit, ops::RangeBounds, ptr, slice};
+
+use crate::{assert_unchecked, error::TryReserveError, is_zero::is_zero_value};
+
+mod drain;
+pub(crate) use drain::Drain;
+
+mod extract_if;
+pub(crate) use extract_if::ExtractIf;
+
+/// Bumps a vector's length to `len` on drop, so that a panic partway through
+/// filling freshly reserved slots still leaves every already-written element
+/// accounted for instead of leaked.
+struct SetLenOnDrop<'v, V: VecImpl + ?Sized> {
+    vec: &'v mut V,
+    len: usize,
+}
+
+impl<V: VecImpl + ?Sized> Drop for SetLenOnDrop<'_, V> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        unsafe { self.vec.set_len(self.len) };
+    }
+}
+
+/// Shifts the untouched tail of a vector down to directly follow the
+/// retained, deduplicated prefix on drop, so that a panicking `same_bucket`
+/// predicate still leaves the vector in a fully compacted, correct-length
+/// state.
+struct FillGapOnDrop<'v, V: VecImpl + ?Sized> {
+    vec: &'v mut V,
+    read: usize,
+    write: usize,
+    original_len: usize,
+}
+
+impl<V: VecImpl + ?Sized> Drop for FillGapOnDrop<'_, V> {
+    fn drop(&mut self) {
+        if self.read != self.write {
+            // SAFETY: the tail `read..original_len` is still initialized and
+            //         untouched; shift it down to directly follow the
+            //         retained prefix.
+            unsafe {
+                ptr::copy(
+                    self.vec.as_ptr().add(self.read),
+                    self.vec.as_ptr_mut().add(self.write),
+                    self.original_len - self.read,
+                );
+            }
+        }
+
+        // SAFETY: every element in `0..write + (original_len - read)` is
+        //         initialized and in its final, compacted position.
+        unsafe {
+            self.vec
+                .set_len(self.write + (self.original_len - self.read));
+        }
+    }
+}
+
+/// Copy or clone every element of `slice` into the `slice.len()` slots
+/// immediately following `vec`'s current length, then bump the length to
+/// match.
+///
+/// When `V::Item: Copy`, this specializes (via the same autoref trick as
+/// `is_zero_value` in `vec/mod.rs`) to a single `ptr::copy_nonoverlapping`
+/// instead of cloning element-by-element.
+///
+/// The caller must have already reserved at least `slice.len()` remaining
+/// capacity.
+unsafe fn extend_from_slice_spec<V: VecImpl + ?Sized>(vec: &mut V, slice: &[V::Item])
+where
+    V::Item: Clone,
+{
+    trait FallbackCopy<T> {
+        #[inline(always)]
+        fn __spec_copy(&self, _dst: *mut T) -> bool {
+            false
+        }
+    }
+
+    struct Spec<'s, T>(&'s [T]);
+
+    impl<T> FallbackCopy<T> for Spec<'_, T> {}
+
+    impl<T: Copy> Spec<'_, T> {
+        #[inline(always)]
+        fn __spec_copy(&self, dst: *mut T) -> bool {
+            // SAFETY: the caller of `extend_from_slice_spec` guarantees `dst`
+            //         has room for `self.0.len()` elements.
+            unsafe { ptr::copy_nonoverlapping(self.0.as_ptr(), dst, self.0.len()) };
+            true
+        }
+    }
+
+    let len = vec.len();
+    let dst = unsafe { vec.as_ptr_mut().add(len) };
+
+    if Spec(slice).__spec_copy(dst) {
+        unsafe { vec.set_len(len + slice.len()) };
+        return;
+    }
+
+    let mut guard = SetLenOnDrop { vec, len };
+    for item in slice {
+        // SAFETY: the caller reserved room for all of `slice`, and `guard.len`
+        //         only ever advances one slot past the last write.
+        unsafe {
+            guard.vec.as_ptr_mut().add(guard.len).write(item.clone());
+        }
+        guard.len += 1;
+    }
+}
+
+/// Trait for implementing vector like data structures.
+#[allow(dead_code)]
+pub(crate) unsafe trait VecImpl {
+    type Item: Sized;
+
+    /// Get the length of the vector.
+    #[must_use]
+    fn len(&self) -> usize;
+
+    /// Returns whether this vector is empty.
+    #[must_use]
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Set the length of the vector.
+    #[track_caller]
+    unsafe fn set_len(&mut self, len: usize);
+
+    /// Get the capacity of the vector.
+    #[must_use]
+    fn capacity(&self) -> usize;
+
+    /// Attempt to grow the internal buffer.
+    fn grow(&mut self, additional: usize) -> Result<(), TryReserveError>;
+
+    /// Attempt to grow the internal buffer exactly.
+    fn grow_exact(&mut self, additional: usize) -> Result<(), TryReserveError>;
+
+    /// Get the remaining uninitialized capacity of the vector.
+    #[must_use]
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        unsafe { self.capacity().unchecked_sub(self.len()) }
+    }
+
+    /// Returns whether the internal buffer will need to grow
+    /// in order to permit a given amount of additional elements.
+    #[inline(always)]
+    #[must_use]
+    fn needs_to_grow(&self, additional: usize) -> bool {
+        additional > self.remaining()
+    }
+
+    /// Attempt to reserve additional space in the vector.
+    #[inline(always)]
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(additional) {
+            self.grow(additional)?;
+        }
+
+        unsafe {
+            assert_unchecked(
+                !self.needs_to_grow(additional),
+                "vector failed to return an error when growing the internal buffer",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Attempt to reserve additional space in the vector exactly.
+    #[inline(always)]
+    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        if self.needs_to_grow(additional) {
+            self.grow_exact(additional)?;
+        }
+
+        unsafe {
+            assert_unchecked(
+                !self.needs_to_grow(additional),
+                "vector failed to return an error when growing the internal buffer",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Reserve additional space in the vector.
+    #[inline(always)]
+    #[track_caller]
+    fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).unwrap();
+    }
+
+    /// Reserve an exact amount of additional space in the vector.
+    #[inline(always)]
+    #[track_caller]
+    fn reserve_exact(&mut self, additional: usize) {
+        self.try_reserve_exact(additional).unwrap();
+    }
+
+    /// Return a raw pointer to the start of the vector's buffer.
+    #[must_use]
+    fn as_ptr(&self) -> *const Self::Item;
+
+    /// Return a mutable raw pointer to the start of the vector's buffer.
+    #[must_use]
+    fn as_ptr_mut(&mut self) -> *mut Self::Item;
+
+    /// Return a slice of the vector's entire buffer.
+    #[must_use]
+    #[inline(always)]
+    fn as_buffer(&self) -> &[MaybeUninit<Self::Item>] {
+        let len = self.capacity();
+        let ptr = self.as_ptr();
+
+        unsafe { slice::from_raw_parts(ptr.cast(), len) }
+    }
+
+    /// Return a mutable slice of the vector's entire buffer.
+    ///
+    /// The caller must ensure no initialized elements are uninitialized.
+    #[must_use]
+    #[inline(always)]
+    fn as_buffer_mut(&mut self) -> &mut [MaybeUninit<Self::Item>] {
+        let len = self.capacity();
+        let ptr = self.as_ptr_mut();
+
+        unsafe { slice::from_raw_parts_mut(ptr.cast(), len) }
+    }
+
+    /// Return a slice of the vector's elements.
+    #[must_use]
+    #[inline(always)]
+    fn as_slice(&self) -> &[Self::Item] {
+        let len = self.len();
+        let ptr = self.as_ptr();
+
+        unsafe { slice::from_raw_parts(ptr.cast(), len) }
+    }
+
+    /// Return a mutable slice of the vector's elements.
+    #[must_use]
+    #[inline(always)]
+    fn as_slice_mut(&mut self) -> &mut [Self::Item] {
+        let len = self.len();
+        let ptr = self.as_ptr_mut();
+
+        unsafe { slice::from_raw_parts_mut(ptr.cast(), len) }
+    }
+
+    /// Return a slice of the remaining uninitialized elements.
+    #[must_use]
+    #[inline(always)]
+    fn as_remaining(&self) -> &[MaybeUninit<Self::Item>] {
+        let len = self.len();
+        let cap = self.capacity();
+
+        let ptr = unsafe { self.as_ptr().add(len) };
+        let len = unsafe { cap.unchecked_sub(len) };
+
+        unsafe { slice::from_raw_parts(ptr.cast(), len) }
+    }
+
+    /// Return a mutable slice of the remaining uninitialized elements.
+    #[must_use]
+    #[inline(always)]
+    fn as_remaining_mut(&mut self) -> &mut [MaybeUninit<Self::Item>] {
+        let len = self.len();
+        let cap = self.capacity();
+
+        let ptr = unsafe { self.as_ptr_mut().add(len) };
+        let len = unsafe { cap.unchecked_sub(len) };
+
+        unsafe { slice::from_raw_parts_mut(ptr.cast(), len) }
+    }
+
+    /// Push an element without checking that it will fit.
+    ///
+    /// The caller must ensure that this will not overflow the buffer.
+    #[inline(always)]
+    fn push_unchecked(&mut self, item: Self::Item) {
+        let len = self.len();
+        let cap = self.capacity();
+
+        debug_assert!(len < cap, "pushing item will overflow");
+
+        unsafe {
+            // Write the item to the buffer.
+            self.as_ptr_mut().add(len).write(item);
+
+            // Increment the length.
+            self.set_len(len.unchecked_add(1));
+        }
+    }
+
+    /// Try to push an element.
+    #[must_use]
+    #[inline(always)]
+    fn try_push(&mut self, item: Self::Item) -> Result<(), (Self::Item, TryReserveError)> {
+        if self.len() == self.capacity() {
+            if let Err(error) = self.grow(1) {
+                return Err((item, error));
+            }
+        }
+
+        // We've grown at least 1 element in size.
+        unsafe {
+            self.push_unchecked(item);
+        }
+
+        Ok(())
+    }
+
+    /// Push an element into the vector.
+    #[must_use]
+    #[inline(always)]
+    #[track_caller]
+    fn push(&mut self, item: Self::Item) {
+        self.try_push(item).map_err(|(_, error)| error).unwrap();
+    }
+
+    /// Pop an element from the vector without checking that it exists.
+    #[must_use]
+    #[inline(always)]
+    unsafe fn pop_unchecked(&mut self) -> Self::Item {
+        let len = self.len();
+
+        debug_assert!(len > 0, "popping item will underflow");
+
+        unsafe {
+            let len = len.unchecked_sub(1);
+            let item = self.as_ptr_mut().add(len).read();
+
+            self.set_len(len);
+
+            item
+        }
+    }
+
+    /// Pop an element from the vector.
+    #[must_use]
+    #[inline(always)]
+    fn pop(&mut self) -> Option<Self::Item> {
+        if self.len() > 0 {
+            Some(unsafe { self.pop_unchecked() })
+        } else {
+            None
+        }
+    }
+
+    /// Shortens the vector, keeping the first `new_len` elements and drops the rest.
+    #[inline(always)]
+    fn truncate(&mut self, new_len: usize) {
+        let len = self.len();
+
+        if new_len < len {
+            unsafe {
+                // Update the length before dropping the elements.
+                self.set_len(new_len);
+
+                let tail = ptr::slice_from_raw_parts_mut(
+                    self.as_ptr_mut().add(new_len),
+                    len.unchecked_sub(new_len),
+                );
+
+                tail.drop_in_place();
+            }
+        }
+    }
+
+    /// Clears the vector, dropping all elements.
+    #[inline(always)]
+    fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Try to insert an element at `index`, shifting every element after it
+    /// one slot to the right.
+    #[must_use]
+    #[track_caller]
+    fn try_insert(
+        &mut self,
+        index: usize,
+        item: Self::Item,
+    ) -> Result<(), (Self::Item, TryReserveError)> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {index}) should be <= len (is {len})"
+        );
+
+        if let Err(error) = self.try_reserve(1) {
+            return Err((item, error));
+        }
+
+        unsafe {
+            let ptr = self.as_ptr_mut().add(index);
+
+            if index < len {
+                ptr::copy(ptr, ptr.add(1), len - index);
+            }
+
+            ptr.write(item);
+            self.set_len(len + 1);
+        }
+
+        Ok(())
+    }
+
+    /// Insert an element at `index`, shifting every element after it one
+    /// slot to the right.
+    #[inline(always)]
+    #[track_caller]
+    fn insert(&mut self, index: usize, item: Self::Item) {
+        self.try_insert(index, item)
+            .map_err(|(_, error)| error)
+            .unwrap();
+    }
+
+    /// Remove and return the element at `index`, shifting every element
+    /// after it one slot to the left.
+    #[must_use]
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> Self::Item {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        unsafe {
+            let ptr = self.as_ptr_mut().add(index);
+            let item = ptr.read();
+
+            ptr::copy(ptr.add(1), ptr, len - index - 1);
+            self.set_len(len - 1);
+
+            item
+        }
+    }
+
+    /// Remove and return the element at `index`, moving the last element
+    /// into its place instead of shifting the tail down.
+    ///
+    /// This does not preserve ordering, but is `O(1)` rather than `O(n)`.
+    #[must_use]
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> Self::Item {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {index}) should be < len (is {len})"
+        );
+
+        unsafe {
+            let ptr = self.as_ptr_mut();
+            let item = ptr.add(index).read();
+
+            let last = len - 1;
+            if index != last {
+                ptr::copy_nonoverlapping(ptr.add(last), ptr.add(index), 1);
+            }
+
+            self.set_len(last);
+
+            item
+        }
+    }
+
+    /// Resize the vector in place so that its length is `new_len`, producing
+    /// each newly added element by calling `f`.
+    ///
+    /// If `new_len` is less than the current length, the vector is
+    /// truncated instead.
+    #[track_caller]
+    fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> Self::Item,
+    {
+        let len = self.len();
+
+        if new_len <= len {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.reserve(new_len - len);
+
+        let mut guard = SetLenOnDrop { vec: self, len };
+        while guard.len < new_len {
+            // SAFETY: We just reserved room for every slot up to `new_len`.
+            unsafe {
+                guard.vec.as_ptr_mut().add(guard.len).write(f());
+            }
+            guard.len += 1;
+        }
+    }
+
+    /// Try to resize the vector in place so that its length is `new_len`,
+    /// filling any newly added slots by cloning `value`.
+    ///
+    /// If `value` happens to be the all-zero-bytes value of `Self::Item`
+    /// (see [`IsZero`](crate::IsZero)), the new slots are filled via a single
+    /// `ptr::write_bytes` instead of cloning `value` one element at a time.
+    ///
+    /// If `new_len` is less than the current length, the vector is
+    /// truncated instead.
+    #[track_caller]
+    fn try_resize(&mut self, new_len: usize, value: Self::Item) -> Result<(), TryReserveError>
+    where
+        Self::Item: Clone,
+    {
+        let len = self.len();
+
+        if new_len <= len {
+            self.truncate(new_len);
+            return Ok(());
+        }
+
+        let additional = new_len - len;
+        self.try_reserve(additional)?;
+
+        if is_zero_value(&value) {
+            // SAFETY: `is_zero_value` only returns `true` when `Self::Item: IsZero`
+            //         attests that the all-zero-bytes pattern is a valid,
+            //         equivalent instance of `value`, and we just reserved
+            //         room for `additional` more elements.
+            unsafe {
+                self.as_ptr_mut().add(len).write_bytes(0, additional);
+                self.set_len(new_len);
+            }
+
+            return Ok(());
+        }
+
+        let mut guard = SetLenOnDrop { vec: self, len };
+        while guard.len < new_len - 1 {
+            // SAFETY: We just reserved room for every slot up to `new_len`.
+            unsafe {
+                guard.vec.as_ptr_mut().add(guard.len).write(value.clone());
+            }
+            guard.len += 1;
+        }
+
+        // SAFETY: See above; the final slot consumes `value` instead of cloning it.
+        unsafe {
+            guard.vec.as_ptr_mut().add(guard.len).write(value);
+        }
+        guard.len += 1;
+
+        Ok(())
+    }
+
+    /// Try to extend this vector with every element of `slice`, cloning each
+    /// one (or, when `Self::Item: Copy`, copying the whole slice in a single
+    /// `ptr::copy_nonoverlapping`).
+    #[track_caller]
+    fn try_extend_from_slice(&mut self, slice: &[Self::Item]) -> Result<(), TryReserveError>
+    where
+        Self::Item: Clone,
+    {
+        self.try_reserve(slice.len())?;
+
+        // SAFETY: We just reserved room for all of `slice`.
+        unsafe { extend_from_slice_spec(self, slice) };
+
+        Ok(())
+    }
+
+    /// Remove consecutive elements for which `same_bucket` returns `true`,
+    /// keeping the first element of each run and dropping the rest in
+    /// place.
+    ///
+    /// If `same_bucket` panics, the elements already visited are left
+    /// compacted and the vector's length updated accordingly; nothing is
+    /// leaked or double-dropped.
+    fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Item, &mut Self::Item) -> bool,
+    {
+        let len = self.len();
+        if len <= 1 {
+            return;
+        }
+
+        // Avoid exposing a half-compacted vector if `same_bucket` panics.
+        unsafe { self.set_len(0) };
+
+        let mut g = FillGapOnDrop {
+            vec: self,
+            read: 1,
+            write: 1,
+            original_len: len,
+        };
+
+        let ptr = g.vec.as_ptr_mut();
+
+        while g.read < len {
+            // SAFETY: `read` is within `1..len`, and every element up to
+            //         `len` is still initialized; `write - 1` is always a
+            //         kept, live element.
+            unsafe {
+                let read_ptr = ptr.add(g.read);
+                let prev_ptr = ptr.add(g.write - 1);
+
+                if same_bucket(&mut *read_ptr, &mut *prev_ptr) {
+                    g.read += 1;
+                    ptr::drop_in_place(read_ptr);
+                } else {
+                    if g.read != g.write {
+                        let write_ptr = ptr.add(g.write);
+                        ptr::copy_nonoverlapping(read_ptr, write_ptr, 1);
+                    }
+
+                    g.read += 1;
+                    g.write += 1;
+                }
+            }
+        }
+    }
+
+    /// Remove consecutive duplicate elements, keeping the first of each run.
+    #[inline(always)]
+    fn dedup(&mut self)
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Remove consecutive elements whose `key` is equal, keeping the first
+    /// of each run.
+    #[inline(always)]
+    fn dedup_by_key<K, F>(&mut self, mut key: F)
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Item) -> K,
+        K: PartialEq,
+    {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    /// Create an iterator which uses a closure to determine which elements to
+    /// remove, yielding those elements and leaving the rest compacted in
+    /// place.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the
+    /// not-yet-scanned tail is left in place, shifted back to directly follow
+    /// the already-compacted prefix, without invoking the predicate again.
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Item) -> bool,
+    {
+        ExtractIf::new(self, pred)
+    }
+
+    /// Remove the given range from this vector, returning a draining
+    /// iterator over the removed elements.
+    ///
+    /// The vector's length is shortened to the start of the range up front
+    /// (leak-safe: if the returned iterator is leaked via
+    /// [`core::mem::forget`] rather than dropped, the drained-and-beyond
+    /// elements simply stay logically removed rather than being exposed
+    /// twice), and the tail is shifted back into place once the iterator is
+    /// dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if the
+    /// end is past the length of the vector.
+    #[must_use]
+    #[track_caller]
+    #[inline(always)]
+    fn drain<R>(&mut self, range: R) -> Drain<'_, Self>
+    where
+        Self: Sized,
+        R: RangeBounds<usize>,
+    {
+        Drain::new(self, range)
+    }
+}